@@ -9,6 +9,10 @@ SUFFIX=""
 
 echo "$TARGET" | grep -E '^x86_64-pc-windows-gnu$' >/dev/null && SUFFIX=".exe"
 
+# the library must keep building and passing its tests without std, for firmware
+cargo build --no-default-features --lib
+cargo test --no-default-features --lib
+
 # build binary
 cross build --target $TARGET --release
 
@@ -0,0 +1,168 @@
+//! Bitcoin addresses of a seed, to check a recombined seed belongs to the wallet you expect by
+//! its first receive address, which is easier to compare than an xpub.
+//!
+//! The purpose of the path picks the address type: BIP44 legacy P2PKH, BIP49 P2SH wrapped
+//! segwit or BIP84 native segwit.
+
+use crate::bip32::{base58check_encode, ExtendedPrivateKey, HARDENED};
+use crate::{bech32, wipe, Mnemonic, SeedXorError};
+use alloc::{string::String, vec::Vec};
+use bitcoin_hashes::{hash160, Hash};
+
+/// The network an address is for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum Network {
+    /// Bitcoin mainnet.
+    #[default]
+    Bitcoin,
+    /// The bitcoin test networks, testnet, signet and regtest share address formats other than
+    /// regtest's `bcrt` segwit prefix, which is not supported.
+    Testnet,
+}
+
+impl Network {
+    /// The BIP44 coin type, hardened in paths.
+    pub fn coin_type(self) -> u32 {
+        match self {
+            Network::Bitcoin => 0,
+            Network::Testnet => 1,
+        }
+    }
+
+    fn p2pkh_version(self) -> u8 {
+        match self {
+            Network::Bitcoin => 0x00,
+            Network::Testnet => 0x6f,
+        }
+    }
+
+    fn p2sh_version(self) -> u8 {
+        match self {
+            Network::Bitcoin => 0x05,
+            Network::Testnet => 0xc4,
+        }
+    }
+
+    fn hrp(self) -> &'static str {
+        match self {
+            Network::Bitcoin => "bc",
+            Network::Testnet => "tb",
+        }
+    }
+}
+
+/// Parse a path like `m/84'/0'/0'/0/0`, hardened with `'` or `h`. A part that isn't the `m` it
+/// starts with or a child number is [SeedXorError::InvalidPath] of its index, counting `m` as
+/// 0.
+fn parse_path(path: &str) -> Result<Vec<u32>, SeedXorError> {
+    let mut parts = path.trim().split('/');
+    if parts.next() != Some("m") {
+        return Err(SeedXorError::InvalidPath(0));
+    }
+    parts
+        .enumerate()
+        .map(|(i, part)| {
+            let (number, hardened) = match part.strip_suffix(['\'', 'h']) {
+                Some(number) => (number, HARDENED),
+                None => (part, 0),
+            };
+            number
+                .parse::<u32>()
+                .ok()
+                .filter(|n| *n < HARDENED)
+                .map(|n| n + hardened)
+                .ok_or(SeedXorError::InvalidPath(i + 1))
+        })
+        .collect()
+}
+
+impl Mnemonic {
+    /// The address at `path` of the seed without a passphrase for `network`, such as
+    /// `m/84'/0'/0'/0/0` for the first BIP84 receive address, see the
+    /// [module docs](crate::address).
+    ///
+    /// A malformed path is [SeedXorError::InvalidPath] of the part, counting `m` as 0, and one
+    /// whose purpose, the first part, is not 44', 49' or 84'
+    /// [SeedXorError::UnsupportedPurpose].
+    pub fn derive_address(&self, path: &str, network: Network) -> Result<String, SeedXorError> {
+        let path = parse_path(path)?;
+        let purpose = path.first().map_or(0, |p| p & !HARDENED);
+        if ![44, 49, 84].contains(&purpose) {
+            return Err(SeedXorError::UnsupportedPurpose(purpose));
+        }
+        let mut seed = self.to_seed_normalized("");
+        let master = ExtendedPrivateKey::master(&seed);
+        wipe(&mut seed);
+        let mut key = master?.derive(&path)?;
+        let pubkey_hash = hash160::Hash::hash(&key.public_key());
+        wipe(&mut key.secret);
+        Ok(match purpose {
+            44 => base58check_encode(&[&[network.p2pkh_version()], &pubkey_hash[..]].concat()),
+            49 => {
+                let script = [&[0x00, 0x14], &pubkey_hash[..]].concat();
+                let script_hash = hash160::Hash::hash(&script);
+                base58check_encode(&[&[network.p2sh_version()], &script_hash[..]].concat())
+            }
+            _ => bech32::encode_segwit_v0(network.hrp(), &pubkey_hash[..]),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::address::*;
+    use std::str::FromStr;
+
+    const SEED: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn derive_address_test_vectors() {
+        let seed = Mnemonic::from_str(SEED).unwrap();
+        // from the BIP84, BIP49 and BIP44 test vectors
+        assert_eq!(
+            "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu",
+            seed.derive_address("m/84'/0'/0'/0/0", Network::Bitcoin)
+                .unwrap()
+        );
+        assert_eq!(
+            "bc1qnjg0jd8228aq7egyzacy8cys3knf9xvrerkf9g",
+            seed.derive_address("m/84h/0h/0h/0/1", Network::Bitcoin)
+                .unwrap()
+        );
+        assert_eq!(
+            "2Mww8dCYPUpKHofjgcXcBCEGmniw9CoaiD2",
+            seed.derive_address("m/49'/1'/0'/0/0", Network::Testnet)
+                .unwrap()
+        );
+        assert_eq!(
+            "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA",
+            seed.derive_address("m/44'/0'/0'/0/0", Network::Bitcoin)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn derive_address_errors() {
+        let seed = Mnemonic::from_str(SEED).unwrap();
+        assert_eq!(
+            Err(SeedXorError::InvalidPath(0)),
+            seed.derive_address("84'/0'/0'/0/0", Network::Bitcoin)
+        );
+        assert_eq!(
+            Err(SeedXorError::InvalidPath(4)),
+            seed.derive_address("m/84'/0'/0'/x/0", Network::Bitcoin)
+        );
+        assert_eq!(
+            Err(SeedXorError::InvalidPath(1)),
+            seed.derive_address("m/2147483648/0", Network::Bitcoin)
+        );
+        assert_eq!(
+            Err(SeedXorError::UnsupportedPurpose(86)),
+            seed.derive_address("m/86'/0'/0'/0/0", Network::Bitcoin)
+        );
+        assert_eq!(
+            Err(SeedXorError::UnsupportedPurpose(0)),
+            seed.derive_address("m", Network::Bitcoin)
+        );
+    }
+}
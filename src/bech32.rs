@@ -0,0 +1,214 @@
+//! [BIP173](https://github.com/bitcoin/bips/blob/master/bip-0173.mediawiki) bech32 encoding of
+//! mnemonic entropy, handy for QR codes or transcribing shares since the checksum catches far
+//! more typos than the bip39 one does.
+
+use crate::{Error, Mnemonic, SeedXorError};
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+const CHECKSUM_LEN: usize = 6;
+
+fn polymod(values: impl Iterator<Item = u8>) -> u32 {
+    values.fold(1u32, |chk, v| {
+        let top = chk >> 25;
+        let chk = ((chk & 0x1ffffff) << 5) ^ v as u32;
+        GENERATOR
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| (top >> i) & 1 == 1)
+            .fold(chk, |chk, (_, g)| chk ^ g)
+    })
+}
+
+fn hrp_expand(hrp: &str) -> impl Iterator<Item = u8> + '_ {
+    hrp.bytes()
+        .map(|b| b >> 5)
+        .chain(core::iter::once(0))
+        .chain(hrp.bytes().map(|b| b & 31))
+}
+
+/// Regroup `data` from `from` bit groups into `to` bit groups, MSB first.
+/// `pad` zero pads the final group, otherwise leftover bits must be zero.
+fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Option<Vec<u8>> {
+    let mut acc = 0u32;
+    let mut bits = 0u32;
+    let mut ret = Vec::with_capacity(data.len() * from as usize / to as usize + 1);
+    let max = (1u32 << to) - 1;
+    for value in data {
+        acc = (acc << from) | *value as u32;
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            ret.push(((acc >> bits) & max) as u8);
+        }
+    }
+    if pad {
+        if bits > 0 {
+            ret.push(((acc << (to - bits)) & max) as u8);
+        }
+    } else if bits >= from || ((acc << (to - bits)) & max) != 0 {
+        return None;
+    }
+    Some(ret)
+}
+
+pub(crate) fn encode(hrp: &str, data: &[u8]) -> String {
+    encode_groups(
+        hrp,
+        &convert_bits(data, 8, 5, true).expect("padding never fails"),
+    )
+}
+
+/// A [BIP173](https://github.com/bitcoin/bips/blob/master/bip-0173.mediawiki#segwit-address-format)
+/// segwit address of witness `version` 0, which still uses bech32 rather than bech32m.
+#[cfg(feature = "bitcoin")]
+pub(crate) fn encode_segwit_v0(hrp: &str, program: &[u8]) -> String {
+    let program = convert_bits(program, 8, 5, true).expect("padding never fails");
+    let data: Vec<u8> = core::iter::once(0).chain(program).collect();
+    encode_groups(hrp, &data)
+}
+
+/// Encode `data`, already in 5 bit groups.
+fn encode_groups(hrp: &str, data: &[u8]) -> String {
+    let hrp = hrp.to_lowercase();
+    let checksum = polymod(
+        hrp_expand(&hrp)
+            .chain(data.iter().copied())
+            .chain([0u8; CHECKSUM_LEN]),
+    ) ^ 1;
+
+    let mut ret = hrp;
+    ret.push('1');
+    ret.extend(data.iter().map(|d| CHARSET[*d as usize] as char));
+    ret.extend(
+        (0..CHECKSUM_LEN)
+            .map(|i| CHARSET[((checksum >> (5 * (CHECKSUM_LEN - 1 - i))) & 31) as usize] as char),
+    );
+    ret
+}
+
+/// Decode a bech32 string into its human-readable part and data bytes.
+///
+/// Anything malformed is reported as [SeedXorError::InvalidBech32] with the character
+/// position, a bad checksum as [Error::InvalidChecksum].
+pub(crate) fn decode(s: &str) -> Result<(String, Vec<u8>), SeedXorError> {
+    if s.chars().any(|c| c.is_ascii_lowercase()) && s.chars().any(|c| c.is_ascii_uppercase()) {
+        return Err(SeedXorError::InvalidBech32(0));
+    }
+    let s = s.to_lowercase();
+    let sep = s.rfind('1').ok_or(SeedXorError::InvalidBech32(0))?;
+    if sep == 0 || sep + 1 + CHECKSUM_LEN > s.len() {
+        return Err(SeedXorError::InvalidBech32(sep));
+    }
+    let (hrp, data) = (&s[..sep], &s[sep + 1..]);
+    if let Some(i) = hrp.bytes().position(|b| !(33..=126).contains(&b)) {
+        return Err(SeedXorError::InvalidBech32(i));
+    }
+    let data = data
+        .bytes()
+        .enumerate()
+        .map(|(i, b)| {
+            CHARSET
+                .iter()
+                .position(|c| *c == b)
+                .map(|p| p as u8)
+                .ok_or(SeedXorError::InvalidBech32(sep + 1 + i))
+        })
+        .collect::<Result<Vec<u8>, SeedXorError>>()?;
+    if polymod(hrp_expand(hrp).chain(data.iter().copied())) != 1 {
+        return Err(Error::InvalidChecksum.into());
+    }
+    let data = convert_bits(&data[..data.len() - CHECKSUM_LEN], 5, 8, false)
+        .ok_or(Error::InvalidChecksum)?;
+    Ok((hrp.to_owned(), data))
+}
+
+impl Mnemonic {
+    /// Encode the entropy as bech32 with the human-readable prefix `hrp`, for example `sxor`.
+    pub fn to_bech32(&self, hrp: &str) -> String {
+        let (entropy, entropy_len) = self.to_entropy_array();
+        encode(hrp, &entropy[0..entropy_len])
+    }
+
+    /// Decode a string made by [Mnemonic::to_bech32] with the same `hrp`, in either case, back
+    /// into an English [Mnemonic]. Another prefix is [SeedXorError::UnexpectedHrp], so the
+    /// bech32 of something else isn't taken for a seed.
+    pub fn from_bech32(hrp: &str, s: &str) -> Result<Mnemonic, SeedXorError> {
+        let (decoded_hrp, entropy) = decode(s)?;
+        if decoded_hrp != hrp.to_lowercase() {
+            return Err(SeedXorError::UnexpectedHrp);
+        }
+        Mnemonic::from_entropy(&entropy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bech32::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn bip173_vectors() {
+        assert_eq!(("a".to_owned(), vec![]), decode("A12UEL5L").unwrap());
+        assert_eq!(
+            ("abcdef".to_owned(), (0..32).collect::<Vec<u8>>()),
+            decode("abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw")
+                .map(|(hrp, data)| (hrp, convert_bits(&data, 8, 5, true).unwrap()))
+                .unwrap()
+        );
+        assert_eq!("a12uel5l", encode("a", &[]));
+        assert_eq!(Err(SeedXorError::InvalidBech32(0)), decode("A12UEL5l"));
+        assert_eq!(Err(SeedXorError::InvalidBech32(0)), decode("a2uel5l"));
+        assert_eq!(Err(SeedXorError::InvalidBech32(3)), decode("a12bel5l"));
+        assert_eq!(Err(Error::InvalidChecksum.into()), decode("a12uel5m"));
+    }
+
+    #[test]
+    fn bech32_roundtrip() {
+        let seed = "romance wink lottery autumn shop bring dawn tongue range crater truth ability miss spice fitness easy legal release recall obey exchange recycle dragon room";
+        let seed = Mnemonic::from_str(seed).unwrap();
+
+        let encoded = seed.to_bech32("sxor");
+        assert!(encoded.starts_with("sxor1"));
+        assert_eq!(seed, Mnemonic::from_bech32("sxor", &encoded).unwrap());
+        assert_eq!(
+            seed,
+            Mnemonic::from_bech32("sxor", &encoded.to_uppercase()).unwrap()
+        );
+        assert_eq!(
+            Err(SeedXorError::UnexpectedHrp),
+            Mnemonic::from_bech32("sxox", &encoded)
+        );
+        assert_eq!(
+            Err(SeedXorError::UnexpectedHrp),
+            Mnemonic::from_bech32("sxor", &seed.to_bech32("bc"))
+        );
+
+        let seed = Mnemonic::from_str(
+            "vault nominee cradle silk own frown throw leg cactus recall talent wisdom",
+        )
+        .unwrap();
+        assert_eq!(
+            seed,
+            Mnemonic::from_bech32("sxor", &seed.to_bech32("sxor")).unwrap()
+        );
+    }
+
+    #[test]
+    fn bech32_detects_corruption() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let encoded = Mnemonic::from_str(seed).unwrap().to_bech32("sxor");
+
+        for i in "sxor1".len()..encoded.len() {
+            let mut corrupted = encoded.clone().into_bytes();
+            corrupted[i] = if corrupted[i] == b'q' { b'p' } else { b'q' };
+            let corrupted = String::from_utf8(corrupted).unwrap();
+            assert_eq!(
+                Err(Error::InvalidChecksum.into()),
+                Mnemonic::from_bech32("sxor", &corrupted),
+                "{corrupted}"
+            );
+        }
+    }
+}
@@ -0,0 +1,314 @@
+//! [BIP32](https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki) derivation of account
+//! extended public keys, to check a recombined seed belongs to the wallet you expect without
+//! having to type it into anything else.
+
+use crate::{secp256k1, wipe, Error, Mnemonic, SeedXorError};
+use alloc::{string::String, vec, vec::Vec};
+use bitcoin_hashes::{hash160, hmac, sha256d, sha512, Hash, HashEngine};
+
+const BASE58: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+/// Child numbers at or above this are hardened.
+pub const HARDENED: u32 = 0x8000_0000;
+
+/// Version bytes of the account extended public key for each BIP purpose, the `xpub` of BIP44
+/// and the [SLIP-132](https://github.com/satoshilabs/slips/blob/master/slip-0132.md) `ypub` and
+/// `zpub` of BIP49 and BIP84, all for bitcoin mainnet.
+const VERSIONS: [(u32, [u8; 4]); 3] = [
+    (44, [0x04, 0x88, 0xb2, 0x1e]),
+    (49, [0x04, 0x9d, 0x7c, 0xb2]),
+    (84, [0x04, 0xb2, 0x47, 0x46]),
+];
+
+pub(crate) fn base58check_encode(data: &[u8]) -> String {
+    let checksum = sha256d::Hash::hash(data);
+    let data: Vec<u8> = data.iter().chain(&checksum[0..4]).copied().collect();
+    // base 58 digits, least significant first
+    let mut digits: Vec<u8> = Vec::with_capacity(data.len() * 138 / 100 + 1);
+    for byte in &data {
+        let mut carry = *byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    let zeros = data.iter().take_while(|b| **b == 0).count();
+    core::iter::repeat_n('1', zeros)
+        .chain(digits.iter().rev().map(|d| BASE58[*d as usize] as char))
+        .collect()
+}
+
+/// Invalid characters are reported as [SeedXorError::InvalidBase58] with their position, a bad
+/// checksum as [Error::InvalidChecksum].
+pub(crate) fn base58check_decode(s: &str) -> Result<Vec<u8>, SeedXorError> {
+    // bytes, least significant first
+    let mut bytes: Vec<u8> = Vec::with_capacity(s.len());
+    for (i, c) in s.bytes().enumerate() {
+        let mut carry = BASE58
+            .iter()
+            .position(|b| *b == c)
+            .ok_or(SeedXorError::InvalidBase58(i))? as u32;
+        for byte in bytes.iter_mut() {
+            carry += *byte as u32 * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    let zeros = s.bytes().take_while(|b| *b == b'1').count();
+    let mut data = vec![0u8; zeros];
+    data.extend(bytes.iter().rev());
+    if data.len() < 4 {
+        return Err(Error::InvalidChecksum.into());
+    }
+    let (data, checksum) = data.split_at(data.len() - 4);
+    if sha256d::Hash::hash(data)[0..4] != *checksum {
+        return Err(Error::InvalidChecksum.into());
+    }
+    Ok(data.to_vec())
+}
+
+pub(crate) fn hmac_sha512(key: &[u8], data: &[&[u8]]) -> ([u8; 32], [u8; 32]) {
+    let mut engine = hmac::HmacEngine::<sha512::Hash>::new(key);
+    for d in data {
+        engine.input(d);
+    }
+    let i = hmac::Hmac::<sha512::Hash>::from_engine(engine);
+    (
+        i[0..32].try_into().expect("32 bytes"),
+        i[32..64].try_into().expect("32 bytes"),
+    )
+}
+
+/// An extended private key, only ever held briefly on the way to a public key.
+pub(crate) struct ExtendedPrivateKey {
+    pub(crate) depth: u8,
+    pub(crate) parent_fingerprint: [u8; 4],
+    pub(crate) child_number: u32,
+    pub(crate) chain_code: [u8; 32],
+    pub(crate) secret: [u8; 32],
+}
+
+/// Reject the vanishingly unlikely keys BIP32 says to skip, as [SeedXorError::InvalidKey].
+fn check_secret(secret: &[u8; 32]) -> Result<(), SeedXorError> {
+    let k = secp256k1::from_be_bytes(secret);
+    if k == [0; 4] || !secp256k1::lt(&k, &secp256k1::N) {
+        return Err(SeedXorError::InvalidKey);
+    }
+    Ok(())
+}
+
+impl ExtendedPrivateKey {
+    pub(crate) fn master(seed: &[u8]) -> Result<Self, SeedXorError> {
+        let (secret, chain_code) = hmac_sha512(b"Bitcoin seed", &[seed]);
+        check_secret(&secret)?;
+        Ok(ExtendedPrivateKey {
+            depth: 0,
+            parent_fingerprint: [0; 4],
+            child_number: 0,
+            chain_code,
+            secret,
+        })
+    }
+
+    pub(crate) fn public_key(&self) -> [u8; 33] {
+        secp256k1::public_key(&self.secret)
+    }
+
+    pub(crate) fn fingerprint(&self) -> [u8; 4] {
+        hash160::Hash::hash(&self.public_key())[0..4]
+            .try_into()
+            .expect("4 bytes")
+    }
+
+    pub(crate) fn child(&self, i: u32) -> Result<Self, SeedXorError> {
+        let (tweak, chain_code) = if i >= HARDENED {
+            hmac_sha512(&self.chain_code, &[&[0], &self.secret, &i.to_be_bytes()])
+        } else {
+            hmac_sha512(&self.chain_code, &[&self.public_key(), &i.to_be_bytes()])
+        };
+        check_secret(&tweak)?;
+        let secret = secp256k1::to_be_bytes(&secp256k1::add_mod(
+            &secp256k1::from_be_bytes(&tweak),
+            &secp256k1::from_be_bytes(&self.secret),
+            &secp256k1::N,
+        ));
+        check_secret(&secret)?;
+        Ok(ExtendedPrivateKey {
+            depth: self.depth.wrapping_add(1),
+            parent_fingerprint: self.fingerprint(),
+            child_number: i,
+            chain_code,
+            secret,
+        })
+    }
+
+    pub(crate) fn derive(&self, path: &[u32]) -> Result<Self, SeedXorError> {
+        let mut key = ExtendedPrivateKey { ..*self };
+        for i in path {
+            let child = key.child(*i);
+            wipe(&mut key.secret);
+            key = child?;
+        }
+        Ok(key)
+    }
+
+    /// The base58check serialized extended public key with `version` bytes.
+    pub(crate) fn to_extended_public(&self, version: [u8; 4]) -> String {
+        let mut data = Vec::with_capacity(78);
+        data.extend_from_slice(&version);
+        data.push(self.depth);
+        data.extend_from_slice(&self.parent_fingerprint);
+        data.extend_from_slice(&self.child_number.to_be_bytes());
+        data.extend_from_slice(&self.chain_code);
+        data.extend_from_slice(&self.public_key());
+        base58check_encode(&data)
+    }
+}
+
+impl Mnemonic {
+    /// The account 0 extended public key for BIP `purpose` 44 (`xpub`), 49 (`ypub`) or
+    /// 84 (`zpub`) of bitcoin mainnet, derived at `m/purpose'/0'/0'` of the seed with
+    /// `passphrase`. Any other purpose is [SeedXorError::UnsupportedPurpose].
+    ///
+    /// The passphrase must already be NFKD normalized, which is a no-op for ASCII.
+    pub fn account_xpub(&self, passphrase: &str, purpose: u32) -> Result<String, SeedXorError> {
+        let (_, version) = VERSIONS
+            .iter()
+            .find(|(p, _)| *p == purpose)
+            .ok_or(SeedXorError::UnsupportedPurpose(purpose))?;
+        let mut seed = self.to_seed_normalized(passphrase);
+        let master = ExtendedPrivateKey::master(&seed);
+        wipe(&mut seed);
+        let mut master = master?;
+        let key = master.derive(&[purpose + HARDENED, HARDENED, HARDENED]);
+        wipe(&mut master.secret);
+        let mut key = key?;
+        let ret = key.to_extended_public(*version);
+        wipe(&mut key.secret);
+        Ok(ret)
+    }
+
+    /// The BIP32 master key fingerprint of the seed with `passphrase`, the first 4 bytes of the
+    /// HASH160 of the master public key, as wallets show it to tell seeds apart without
+    /// revealing them.
+    ///
+    /// The passphrase must already be NFKD normalized, which is a no-op for ASCII.
+    pub fn fingerprint(&self, passphrase: &str) -> [u8; 4] {
+        let mut seed = self.to_seed_normalized(passphrase);
+        let master = ExtendedPrivateKey::master(&seed);
+        wipe(&mut seed);
+        // an invalid master key has a chance of about 1 in 2^127
+        let mut master = master.expect("valid master key");
+        let ret = master.fingerprint();
+        wipe(&mut master.secret);
+        ret
+    }
+
+    /// Whether `xpub`, an account 0 `xpub`, `ypub` or `zpub`, is derived from this seed with
+    /// `passphrase`, see [Mnemonic::account_xpub].
+    ///
+    /// An `xpub` that doesn't decode is an error, as is one of the wrong length or whose version
+    /// bytes are not one of the three, [SeedXorError::InvalidXpub].
+    pub fn verify_xpub(&self, passphrase: &str, xpub: &str) -> Result<bool, SeedXorError> {
+        let data = base58check_decode(xpub.trim())?;
+        let purpose = data
+            .get(0..4)
+            .filter(|_| data.len() == 78)
+            .and_then(|version| VERSIONS.iter().find(|(_, v)| v == version))
+            .map(|(purpose, _)| *purpose)
+            .ok_or(SeedXorError::InvalidXpub)?;
+        Ok(self.account_xpub(passphrase, purpose)? == xpub.trim())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bip32::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn base58check() {
+        let data = [0, 0, 1, 2, 3, 255];
+        let encoded = base58check_encode(&data);
+        assert!(encoded.starts_with("11"));
+        assert_eq!(data.to_vec(), base58check_decode(&encoded).unwrap());
+        assert_eq!(
+            Err(SeedXorError::InvalidBase58(1)),
+            base58check_decode("10OI")
+        );
+        let mut bad = encoded.into_bytes();
+        bad[3] = if bad[3] == b'2' { b'3' } else { b'2' };
+        assert_eq!(
+            Err(Error::InvalidChecksum.into()),
+            base58check_decode(&String::from_utf8(bad).unwrap())
+        );
+    }
+
+    #[test]
+    fn bip32_test_vector_1() {
+        let seed: Vec<u8> = (0..16).collect();
+        let xpub = [0x04, 0x88, 0xb2, 0x1e];
+        let master = ExtendedPrivateKey::master(&seed).unwrap();
+        assert_eq!(
+            "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8",
+            master.to_extended_public(xpub)
+        );
+        assert_eq!(
+            "xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw",
+            master.derive(&[HARDENED]).unwrap().to_extended_public(xpub)
+        );
+        // m/0'/1 is not hardened
+        assert_eq!(
+            "xpub6ASuArnXKPbfEwhqN6e3mwBcDTgzisQN1wXN9BJcM47sSikHjJf3UFHKkNAWbWMiGj7Wf5uMash7SyYq527Hqck2AxYysAA7xmALppuCkwQ",
+            master.derive(&[HARDENED, 1]).unwrap().to_extended_public(xpub)
+        );
+    }
+
+    #[test]
+    fn master_fingerprint() {
+        let seed = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let seed = Mnemonic::from_str(seed).unwrap();
+        // the key origin wallets show for this seed, checked with a python implementation
+        assert_eq!([0x73, 0xc5, 0xda, 0x0a], seed.fingerprint(""));
+        assert_ne!(seed.fingerprint(""), seed.fingerprint("TREZOR"));
+    }
+
+    #[test]
+    fn account_xpub_and_verify() {
+        let seed = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let seed = Mnemonic::from_str(seed).unwrap();
+
+        // from the BIP84 test vectors
+        let zpub = "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs";
+        assert_eq!(zpub, seed.account_xpub("", 84).unwrap());
+        assert_eq!(Ok(true), seed.verify_xpub("", zpub));
+        assert_eq!(Ok(false), seed.verify_xpub("TREZOR", zpub));
+
+        let xpub = seed.account_xpub("", 44).unwrap();
+        assert!(xpub.starts_with("xpub"));
+        assert_eq!(Ok(true), seed.verify_xpub("", &xpub));
+        assert!(seed.account_xpub("", 49).unwrap().starts_with("ypub"));
+        assert_eq!(
+            Err(SeedXorError::UnsupportedPurpose(86)),
+            seed.account_xpub("", 86)
+        );
+
+        let other = Mnemonic::from_str(
+            "silent toe meat possible chair blossom wait occur this worth option boy",
+        )
+        .unwrap();
+        assert_eq!(Ok(false), other.verify_xpub("", zpub));
+        assert_eq!(
+            Err(SeedXorError::InvalidXpub),
+            seed.verify_xpub("", &base58check_encode(&[1, 2, 3, 4, 5, 6]))
+        );
+    }
+}
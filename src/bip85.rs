@@ -0,0 +1,165 @@
+//! [BIP85](https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki) deterministic child
+//! mnemonics, so one recombined seed can back up any number of independent wallets.
+
+use crate::bip32::{hmac_sha512, ExtendedPrivateKey, HARDENED};
+use crate::{wipe, Error, Language, Mnemonic, SeedXorError};
+
+/// BIP85 purpose, `DRNG` in ascii digits.
+const PURPOSE: u32 = 83696968;
+/// BIP85 application number of bip39 mnemonics.
+const APP_BIP39: u32 = 39;
+
+/// BIP85 language code of `language`, found by its 10th word as the two Chinese wordlists only
+/// differ from there.
+fn language_code(language: Language) -> u32 {
+    const CODES: [(u32, &str); 9] = [
+        (0, "abuse"),
+        (1, "あさい"),
+        // NFKD like the wordlists, 가슴
+        (2, "\u{1100}\u{1161}\u{1109}\u{1173}\u{11b7}"),
+        (3, "abuelo"),
+        (4, "这"),
+        (5, "這"),
+        (6, "abreuver"),
+        (7, "accenno"),
+        (8, "ananas"),
+    ];
+    CODES
+        .iter()
+        .find(|(_, word)| language.word_list()[9] == *word)
+        .map(|(code, _)| *code)
+        .expect("every bip39 language has a code")
+}
+
+/// The child mnemonic at `m/83696968'/39'/language'/word_count'/index'` of `root`.
+fn derive_mnemonic(
+    root: &ExtendedPrivateKey,
+    language: Language,
+    word_count: usize,
+    index: u32,
+) -> Result<Mnemonic, SeedXorError> {
+    let entropy_len = match word_count {
+        12 | 18 | 24 => word_count / 3 * 4,
+        _ => return Err(Error::BadWordCount(word_count).into()),
+    };
+    if index >= HARDENED {
+        return Err(SeedXorError::IndexOutOfRange {
+            index: index as usize,
+            len: HARDENED as usize,
+        });
+    }
+    let mut key = root.derive(&[
+        PURPOSE + HARDENED,
+        APP_BIP39 + HARDENED,
+        language_code(language) + HARDENED,
+        word_count as u32 + HARDENED,
+        index + HARDENED,
+    ])?;
+    let (mut entropy, mut rest) = hmac_sha512(b"bip-entropy-from-k", &[&key.secret]);
+    let ret = bip39::Mnemonic::from_entropy_in(language, &entropy[0..entropy_len]);
+    wipe(&mut entropy);
+    wipe(&mut rest);
+    wipe(&mut key.secret);
+    Ok(ret?.into())
+}
+
+impl Mnemonic {
+    /// The BIP85 child mnemonic number `index` of `word_count` words in `language`, derived
+    /// from this seed without a passphrase.
+    ///
+    /// BIP85 only defines 12, 18 and 24 words, any other count is [Error::BadWordCount] of the
+    /// count, and an `index` of 2^31 or more, which can't be hardened, is
+    /// [SeedXorError::IndexOutOfRange].
+    pub fn derive_bip85(
+        &self,
+        language: Language,
+        word_count: usize,
+        index: u32,
+    ) -> Result<Mnemonic, SeedXorError> {
+        let mut seed = self.to_seed_normalized("");
+        let root = ExtendedPrivateKey::master(&seed);
+        wipe(&mut seed);
+        let mut root = root?;
+        let ret = derive_mnemonic(&root, language, word_count, index);
+        wipe(&mut root.secret);
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bip32::base58check_decode;
+    use crate::bip85::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn bip85_test_vectors() {
+        let xprv = base58check_decode("xprv9s21ZrQH143K2LBWUUQRFXhucrQqBpKdRRxNVq2zBqsx8HVqFk2uYo8kmbaLLHRdqtQpUm98uKfu3vca1LqdGhUtyoFnCNkfmXRyPXLjbKb").unwrap();
+        let root = ExtendedPrivateKey {
+            depth: 0,
+            parent_fingerprint: [0; 4],
+            child_number: 0,
+            chain_code: xprv[13..45].try_into().unwrap(),
+            secret: xprv[46..78].try_into().unwrap(),
+        };
+        for expected in [
+            "girl mad pet galaxy egg matter matrix prison refuse sense ordinary nose",
+            "near account window bike charge season chef number sketch tomorrow excuse sniff circle vital hockey outdoor supply token",
+            "puppy ocean match cereal symbol another shed magic wrap hammer bulb intact gadget divorce twin tonight reason outdoor destroy simple truth cigar social volcano",
+        ] {
+            let expected = Mnemonic::from_str(expected).unwrap();
+            assert_eq!(
+                expected,
+                derive_mnemonic(&root, Language::English, expected.word_count(), 0).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn derive_bip85_from_mnemonic() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let seed = Mnemonic::from_str(seed).unwrap();
+        assert_eq!(
+            Mnemonic::from_str(
+                "element feel warrior hint inject fit web crane motion misery buddy also"
+            )
+            .unwrap(),
+            seed.derive_bip85(Language::English, 12, 1).unwrap()
+        );
+        assert_ne!(
+            seed.derive_bip85(Language::English, 24, 0).unwrap(),
+            seed.derive_bip85(Language::French, 24, 0).unwrap()
+        );
+        assert_eq!(
+            Language::French,
+            seed.derive_bip85(Language::French, 24, 0)
+                .unwrap()
+                .language()
+        );
+
+        for word_count in [0, 15, 21, 25] {
+            assert_eq!(
+                Err(Error::BadWordCount(word_count).into()),
+                seed.derive_bip85(Language::English, word_count, 0)
+            );
+        }
+        assert_eq!(
+            Err(SeedXorError::IndexOutOfRange {
+                index: HARDENED as usize,
+                len: HARDENED as usize
+            }),
+            seed.derive_bip85(Language::English, 12, HARDENED)
+        );
+    }
+
+    #[test]
+    fn language_codes() {
+        assert_eq!(0, language_code(Language::English));
+        assert_eq!(4, language_code(Language::SimplifiedChinese));
+        assert_eq!(5, language_code(Language::TraditionalChinese));
+        assert_eq!(8, language_code(Language::Czech));
+        for language in Language::all() {
+            language_code(*language);
+        }
+    }
+}
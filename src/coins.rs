@@ -0,0 +1,139 @@
+//! Entropy from coin flips, one bit per flip, for air-gapped users who would rather not trust
+//! the computer's randomness and have no dice, see [Mnemonic::from_dice] for those.
+
+use crate::{entropy_len_from_word_count, wipe, EntropySource, Error, Mnemonic, SeedXorError};
+use alloc::vec;
+
+impl Mnemonic {
+    /// An English mnemonic of `word_count` words whose entropy is `flips`, heads being 1, the
+    /// first flip the most significant bit.
+    ///
+    /// An invalid word count is [Error::BadWordCount], and anything but exactly the entropy
+    /// bits of `word_count` words, 128 for 12 words up to 256 for 24, of flips is
+    /// [SeedXorError::LengthMismatch].
+    pub fn from_coin_flips(flips: &[bool], word_count: usize) -> Result<Self, SeedXorError> {
+        let len = entropy_len_from_word_count(word_count).ok_or(Error::BadWordCount(word_count))?;
+        if flips.len() != len * 8 {
+            return Err(SeedXorError::LengthMismatch {
+                expected: len * 8,
+                got: flips.len(),
+            });
+        }
+        let mut entropy = vec![0u8; len];
+        for (i, _) in flips.iter().enumerate().filter(|(_, heads)| **heads) {
+            entropy[i / 8] |= 1 << (7 - i % 8);
+        }
+        let ret = Self::from_entropy(&entropy);
+        wipe(&mut entropy);
+        ret
+    }
+}
+
+/// Coin flips as an [EntropySource], to split with or generate from coins, every byte filled
+/// uses up 8 flips packed like [Mnemonic::from_coin_flips].
+///
+/// Running out of flips is [SeedXorError::LengthMismatch] of the flips needed and left.
+#[derive(Clone, Debug)]
+pub struct CoinSource<'a> {
+    flips: &'a [bool],
+}
+
+impl<'a> CoinSource<'a> {
+    /// Flips with heads as `true`.
+    pub fn new(flips: &'a [bool]) -> Self {
+        CoinSource { flips }
+    }
+
+    /// Flips not used up yet.
+    pub fn remaining(&self) -> usize {
+        self.flips.len()
+    }
+}
+
+impl EntropySource for CoinSource<'_> {
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), SeedXorError> {
+        if self.flips.len() < dest.len() * 8 {
+            return Err(SeedXorError::LengthMismatch {
+                expected: dest.len() * 8,
+                got: self.flips.len(),
+            });
+        }
+        let (flips, rest) = self.flips.split_at(dest.len() * 8);
+        for (b, flips) in dest.iter_mut().zip(flips.chunks(8)) {
+            *b = flips.iter().fold(0, |b, heads| b << 1 | *heads as u8);
+        }
+        self.flips = rest;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::coins::*;
+    use crate::{Language, SeedXor};
+
+    #[test]
+    fn from_coin_flips() {
+        let flips: Vec<bool> = (0..128).map(|i| i % 8 == 0 || i % 8 == 7).collect();
+        assert_eq!(
+            Mnemonic::from([0x81u8; 16]),
+            Mnemonic::from_coin_flips(&flips, 12).unwrap()
+        );
+        assert_eq!(
+            Mnemonic::from([0xffu8; 32]),
+            Mnemonic::from_coin_flips(&[true; 256], 24).unwrap()
+        );
+        let mut flips = [false; 160];
+        flips[0] = true;
+        flips[159] = true;
+        let mut entropy = [0u8; 20];
+        entropy[0] = 0x80;
+        entropy[19] = 0x01;
+        assert_eq!(
+            Mnemonic::from_entropy(&entropy).unwrap(),
+            Mnemonic::from_coin_flips(&flips, 15).unwrap()
+        );
+    }
+
+    #[test]
+    fn coin_source() {
+        let flips: Vec<bool> = (0..400).map(|i| i % 3 == 0).collect();
+        let mut source = CoinSource::new(&flips);
+        let seed = Mnemonic::generate_in_with_source(Language::English, 12, &mut source).unwrap();
+        assert_eq!(Mnemonic::from_coin_flips(&flips[..128], 12).unwrap(), seed);
+        assert_eq!(272, source.remaining());
+
+        let shares = seed.clone().splitn_with_rng(3, &mut source).unwrap();
+        assert_eq!(seed, Mnemonic::xor_all(&shares).unwrap());
+        assert_eq!(16, source.remaining());
+        assert_eq!(
+            Err(SeedXorError::LengthMismatch {
+                expected: 128,
+                got: 16
+            }),
+            seed.split_with_rng(&mut source)
+        );
+    }
+
+    #[test]
+    fn from_coin_flips_errors() {
+        assert_eq!(
+            Err(SeedXorError::LengthMismatch {
+                expected: 256,
+                got: 255
+            }),
+            Mnemonic::from_coin_flips(&[true; 255], 24)
+        );
+        assert_eq!(
+            Err(SeedXorError::LengthMismatch {
+                expected: 128,
+                got: 129
+            }),
+            Mnemonic::from_coin_flips(&[true; 129], 12)
+        );
+        assert_eq!(
+            Err(Error::BadWordCount(13).into()),
+            Mnemonic::from_coin_flips(&[true; 128], 13)
+        );
+    }
+}
@@ -0,0 +1,128 @@
+//! One call diagnosis of why a mnemonic doesn't parse, for import screens that want to tell the
+//! user exactly what to fix rather than just show a [bip39::Error].
+
+use crate::{checksum_bits_for_words, Error, Language, Mnemonic};
+use alloc::{string::String, string::ToString, vec::Vec};
+
+/// Result of [Mnemonic::diagnose].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Diagnosis {
+    /// A valid bip39 mnemonic.
+    Valid,
+    /// Every word is known but the checksum is wrong, `fixed_last_word` is the last word with
+    /// the same entropy bits and a correct checksum, like [Mnemonic::checksum_fix].
+    BadChecksum { fixed_last_word: &'static str },
+    /// Not 12, 15, 18, 21 or 24 words.
+    BadWordCount { got: usize },
+    /// Position and text of every word not in the wordlist.
+    UnknownWords(Vec<(usize, String)>),
+    /// Every word is in more than one wordlist, pass the language explicitly.
+    AmbiguousLanguage,
+}
+
+impl Mnemonic {
+    /// Work out what, if anything, is wrong with the mnemonic `s`, detecting the language
+    /// unless `language` is given.
+    ///
+    /// Words are expected to be full lowercase words, prefixes are not expanded.
+    pub fn diagnose(s: &str, language: Option<Language>) -> Diagnosis {
+        let words: Vec<&str> = s.split_whitespace().collect();
+        let got = words.len();
+        if !(12..=24).contains(&got) || !got.is_multiple_of(3) {
+            return Diagnosis::BadWordCount { got };
+        }
+        let language =
+            match language.map_or_else(|| bip39::Mnemonic::language_of(words.join(" ")), Ok) {
+                Ok(language) => language,
+                Err(Error::AmbiguousLanguages(_)) => return Diagnosis::AmbiguousLanguage,
+                // report unknown words against whichever language knows the most of them
+                Err(_) => Language::all()
+                    .iter()
+                    .copied()
+                    .rev()
+                    .max_by_key(|l| words.iter().filter(|w| l.find_word(w).is_some()).count())
+                    .unwrap_or(Language::English),
+            };
+        let unknown: Vec<(usize, String)> = words
+            .iter()
+            .enumerate()
+            .filter(|(_, w)| language.find_word(w).is_none())
+            .map(|(i, w)| (i, w.to_string()))
+            .collect();
+        if !unknown.is_empty() {
+            return Diagnosis::UnknownWords(unknown);
+        }
+        match bip39::Mnemonic::parse_in_normalized(language, &words.join(" ")) {
+            Ok(_) => Diagnosis::Valid,
+            Err(_) => {
+                // valid last words are ordered by their entropy bits, the top bits of the index
+                let (last, rest) = words.split_last().expect("cannot be empty");
+                let entropy_bits = language.find_word(last).expect("already checked")
+                    >> checksum_bits_for_words(got);
+                let fixed = Mnemonic::valid_last_words_in(language, rest)
+                    .expect("word count and words already checked");
+                Diagnosis::BadChecksum {
+                    fixed_last_word: fixed[entropy_bits as usize],
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diagnose::*;
+
+    #[test]
+    fn diagnose_each_kind() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        assert_eq!(Diagnosis::Valid, Mnemonic::diagnose(seed, None));
+        assert_eq!(
+            Diagnosis::Valid,
+            Mnemonic::diagnose(&format!(" {seed}\n"), Some(Language::English))
+        );
+
+        let electrum_seed =
+            "ramp exotic resource icon sun addict equip sand leisure spare swing tobacco";
+        assert_eq!(
+            Diagnosis::BadChecksum {
+                fixed_last_word: "toast"
+            },
+            Mnemonic::diagnose(electrum_seed, None)
+        );
+
+        assert_eq!(
+            Diagnosis::BadWordCount { got: 11 },
+            Mnemonic::diagnose(
+                "silent toe meat possible chair blossom wait occur this worth option",
+                None
+            )
+        );
+        assert_eq!(
+            Diagnosis::BadWordCount { got: 0 },
+            Mnemonic::diagnose("", None)
+        );
+
+        let typos = "silent toe meat possibel chair blossom wait occur this worth optoin boy";
+        let expected =
+            Diagnosis::UnknownWords(vec![(3, "possibel".to_owned()), (10, "optoin".to_owned())]);
+        assert_eq!(expected, Mnemonic::diagnose(typos, None));
+        assert_eq!(expected, Mnemonic::diagnose(typos, Some(Language::English)));
+        assert_eq!(
+            Diagnosis::UnknownWords(vec![(0, "silent".to_owned()), (1, "toe".to_owned())]),
+            Mnemonic::diagnose(
+                "silent toe abandon amateur angle animal aspect badge bicycle bonus brave canal",
+                Some(Language::French)
+            )
+        );
+
+        // every word is in both the English and French wordlists
+        let both =
+            "abandon amateur angle animal aspect badge bicycle bonus brave canal capable caution";
+        assert_eq!(Diagnosis::AmbiguousLanguage, Mnemonic::diagnose(both, None));
+        assert_ne!(
+            Diagnosis::AmbiguousLanguage,
+            Mnemonic::diagnose(both, Some(Language::English))
+        );
+    }
+}
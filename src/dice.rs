@@ -0,0 +1,188 @@
+//! Entropy from casino dice, for air-gapped users who would rather not trust the computer's
+//! randomness, the same way a
+//! [Coldcard](https://coldcard.com/docs/verifying-dice-roll-math/) does it: the SHA256 of the
+//! rolls typed as the digits 1 to 6, truncated to the entropy size.
+
+use crate::{entropy_len_from_word_count, EntropySource, Error, Mnemonic, SeedXorError};
+use alloc::vec::Vec;
+use bitcoin_hashes::{sha256, Hash};
+
+/// log2(6) times 10^15, in integers as float functions like `log2` and `ceil` need `std`.
+const LOG2_6: u128 = 2_584_962_500_721_156;
+
+/// Fewest rolls of a D6 with at least `bits` of entropy, each roll is log2(6), about 2.58, bits.
+pub fn min_dice_rolls(bits: usize) -> usize {
+    (bits as u128 * 1_000_000_000_000_000).div_ceil(LOG2_6) as usize
+}
+
+impl Mnemonic {
+    /// An English mnemonic of `word_count` words from the D6 `rolls`, each 1 to 6, see the
+    /// [module docs](crate::dice), so shares or seeds made this way can be checked on a
+    /// Coldcard.
+    ///
+    /// The rolls are not read as a base 6 number with 6 as 0, they are hashed as the ASCII
+    /// digits 1 to 6 like a Coldcard does, so the same rolls make the same seed on both.
+    ///
+    /// An invalid word count is [Error::BadWordCount], a roll not from 1 to 6 is
+    /// [SeedXorError::InvalidRoll] with its index, and fewer than [min_dice_rolls] for the entropy of
+    /// `word_count` words is [SeedXorError::LengthMismatch].
+    pub fn from_dice(rolls: &[u8], word_count: usize) -> Result<Self, SeedXorError> {
+        let len = entropy_len_from_word_count(word_count).ok_or(Error::BadWordCount(word_count))?;
+        if let Some(i) = rolls.iter().position(|r| !(1..=6).contains(r)) {
+            return Err(SeedXorError::InvalidRoll(i));
+        }
+        let expected = min_dice_rolls(len * 8);
+        if rolls.len() < expected {
+            return Err(SeedXorError::LengthMismatch {
+                expected,
+                got: rolls.len(),
+            });
+        }
+        let mut digits: Vec<u8> = rolls.iter().map(|r| b'0' + r).collect();
+        let hash = sha256::Hash::hash(&digits);
+        crate::wipe(&mut digits);
+        Self::from_entropy(&hash[0..len])
+    }
+}
+
+/// D6 rolls as an [EntropySource], to split with or generate from dice, every 32 bytes or less
+/// that are filled use up the [min_dice_rolls] for them, the SHA256 of which is truncated to
+/// them like [Mnemonic::from_dice], so generating from exactly that many rolls makes the same
+/// mnemonic.
+///
+/// Running out of rolls is [SeedXorError::LengthMismatch] of the rolls needed and left.
+#[derive(Clone, Debug)]
+pub struct DiceSource<'a> {
+    rolls: &'a [u8],
+}
+
+impl<'a> DiceSource<'a> {
+    /// Rolls each 1 to 6, any other is [SeedXorError::InvalidRoll] with its index.
+    pub fn new(rolls: &'a [u8]) -> Result<Self, SeedXorError> {
+        match rolls.iter().position(|r| !(1..=6).contains(r)) {
+            Some(i) => Err(SeedXorError::InvalidRoll(i)),
+            None => Ok(DiceSource { rolls }),
+        }
+    }
+
+    /// Rolls not used up yet.
+    pub fn remaining(&self) -> usize {
+        self.rolls.len()
+    }
+}
+
+impl EntropySource for DiceSource<'_> {
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), SeedXorError> {
+        let expected: usize = dest.chunks(32).map(|c| min_dice_rolls(c.len() * 8)).sum();
+        if self.rolls.len() < expected {
+            return Err(SeedXorError::LengthMismatch {
+                expected,
+                got: self.rolls.len(),
+            });
+        }
+        for chunk in dest.chunks_mut(32) {
+            let (rolls, rest) = self.rolls.split_at(min_dice_rolls(chunk.len() * 8));
+            let mut digits: Vec<u8> = rolls.iter().map(|r| b'0' + r).collect();
+            let hash = sha256::Hash::hash(&digits);
+            crate::wipe(&mut digits);
+            chunk.copy_from_slice(&hash[0..chunk.len()]);
+            self.rolls = rest;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dice::*;
+    use crate::{Language, SeedXor};
+
+    #[test]
+    fn min_rolls() {
+        assert_eq!(
+            vec![50, 62, 75, 87, 100],
+            [128, 160, 192, 224, 256].map(min_dice_rolls).to_vec()
+        );
+    }
+
+    #[test]
+    fn from_dice() {
+        // sha256 of the digits, computed with coreutils sha256sum
+        let rolls: Vec<u8> = (0..100).map(|i| i % 6 + 1).collect();
+        let hash = [
+            0xe5, 0x64, 0x03, 0xe8, 0x52, 0x2d, 0xde, 0xae, 0x1b, 0x44, 0xa1, 0xe8, 0x14, 0x8b,
+            0x1b, 0xa4, 0xd3, 0xb4, 0xc6, 0x26, 0xcc, 0xf2, 0x09, 0x80, 0x05, 0x6e, 0xed, 0xcc,
+            0x7e, 0x0c, 0x0f, 0x35,
+        ];
+        assert_eq!(
+            Mnemonic::from_entropy(&hash).unwrap(),
+            Mnemonic::from_dice(&rolls, 24).unwrap()
+        );
+
+        let rolls = [1u8; 50];
+        let hash = [
+            0x3d, 0xac, 0x51, 0xa6, 0x5e, 0xc9, 0xfc, 0xfc, 0x40, 0x9a, 0x1b, 0x5f, 0x1d, 0xef,
+            0xe9, 0x2b,
+        ];
+        assert_eq!(
+            Mnemonic::from_entropy(&hash).unwrap(),
+            Mnemonic::from_dice(&rolls, 12).unwrap()
+        );
+    }
+
+    #[test]
+    fn dice_source() {
+        let rolls: Vec<u8> = (0..150).map(|i| i % 6 + 1).collect();
+        let mut source = DiceSource::new(&rolls).unwrap();
+        let seed = Mnemonic::generate_in_with_source(Language::English, 12, &mut source).unwrap();
+        assert_eq!(Mnemonic::from_dice(&rolls[..50], 12).unwrap(), seed);
+        assert_eq!(100, source.remaining());
+
+        let shares = seed.clone().splitn_with_rng(3, &mut source).unwrap();
+        assert_eq!(seed, Mnemonic::xor_all(&shares).unwrap());
+        assert_eq!(
+            Err(SeedXorError::LengthMismatch {
+                expected: 50,
+                got: 0
+            }),
+            seed.split_with_rng(&mut source)
+        );
+        assert_eq!(
+            Err(SeedXorError::InvalidRoll(2)),
+            DiceSource::new(&[1, 6, 7]).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn from_dice_errors() {
+        assert_eq!(
+            Err(SeedXorError::LengthMismatch {
+                expected: 100,
+                got: 99
+            }),
+            Mnemonic::from_dice(&[3; 99], 24)
+        );
+        assert_eq!(
+            Err(SeedXorError::LengthMismatch {
+                expected: 50,
+                got: 49
+            }),
+            Mnemonic::from_dice(&[3; 49], 12)
+        );
+        let mut rolls = [3u8; 50];
+        rolls[7] = 0;
+        assert_eq!(
+            Err(SeedXorError::InvalidRoll(7)),
+            Mnemonic::from_dice(&rolls, 12)
+        );
+        rolls[7] = 7;
+        assert_eq!(
+            Err(SeedXorError::InvalidRoll(7)),
+            Mnemonic::from_dice(&rolls, 12)
+        );
+        assert_eq!(
+            Err(Error::BadWordCount(13).into()),
+            Mnemonic::from_dice(&[3; 200], 13)
+        );
+    }
+}
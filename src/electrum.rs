@@ -0,0 +1,271 @@
+//! [Electrum](https://electrum.readthedocs.io/en/latest/seedphrase.html) seeds, which are
+//! English words from the bip39 wordlist but carry a version instead of a bip39 checksum: the
+//! HMAC-SHA512 of the words, keyed with `Seed version`, must start with the version's hex
+//! prefix.
+//!
+//! Splitting and combining XORs every bit of every word, like [RebuildMode::PreserveBytes],
+//! so the shares combine back to the exact same words and that version. The shares themselves
+//! are random words and almost never valid Electrum seeds.
+
+use crate::{EntropySource, Error, Language, Mnemonic, RebuildMode, SeedXor, SeedXorError};
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+use alloc::{string::String, vec};
+use bitcoin_hashes::{hmac, sha512, Hash, HashEngine};
+use core::{fmt, str::FromStr};
+
+/// Version of an [ElectrumMnemonic], which decides the wallet type Electrum restores.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ElectrumVersion {
+    /// Legacy P2PKH wallets, prefix `01`.
+    Standard,
+    /// Native segwit P2WPKH wallets, prefix `100`.
+    Segwit,
+}
+
+impl ElectrumVersion {
+    /// The hex prefix the HMAC of the words starts with.
+    pub fn prefix(self) -> &'static str {
+        match self {
+            ElectrumVersion::Standard => "01",
+            ElectrumVersion::Segwit => "100",
+        }
+    }
+
+    /// The version of `words`, which must already be normalized, lowercase and single spaced.
+    fn of(words: &str) -> Option<Self> {
+        let mut engine = hmac::HmacEngine::<sha512::Hash>::new(b"Seed version");
+        engine.input(words.as_bytes());
+        let hash = hmac::Hmac::<sha512::Hash>::from_engine(engine).into_inner();
+        if hash[0] == 0x01 {
+            Some(ElectrumVersion::Standard)
+        } else if hash[0] == 0x10 && hash[1] >> 4 == 0 {
+            Some(ElectrumVersion::Segwit)
+        } else {
+            None
+        }
+    }
+}
+
+/// An Electrum seed, or a share of one, see the [module docs](crate::electrum).
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ElectrumMnemonic {
+    inner: Mnemonic,
+}
+
+impl ElectrumMnemonic {
+    /// A random Electrum seed of `version` with `word_count` words, anything but a valid bip39
+    /// word count is [Error::BadWordCount].
+    #[cfg(feature = "std")]
+    pub fn generate(version: ElectrumVersion, word_count: usize) -> Result<Self, SeedXorError> {
+        Self::generate_with_rng(version, word_count, &mut crate::OsEntropy)
+    }
+
+    /// [ElectrumMnemonic::generate] with the words drawn from `rng`.
+    ///
+    /// Random words are drawn until they happen to have `version`, which takes 256 tries on
+    /// average for [ElectrumVersion::Standard] and 4096 for [ElectrumVersion::Segwit].
+    pub fn generate_with_rng<R: EntropySource + ?Sized>(
+        version: ElectrumVersion,
+        word_count: usize,
+        rng: &mut R,
+    ) -> Result<Self, SeedXorError> {
+        if crate::entropy_len_from_word_count(word_count).is_none() {
+            return Err(Error::BadWordCount(word_count).into());
+        }
+        let list = Language::English.word_list();
+        let mut indices = vec![0u8; word_count * 2];
+        let mut words = String::with_capacity(word_count * 9);
+        let ret = loop {
+            if let Err(e) = rng.fill_bytes(&mut indices) {
+                break Err(e);
+            }
+            words.clear();
+            for index in indices.chunks(2) {
+                words.push_str(list[u16::from_be_bytes([index[0], index[1]]) as usize & 0x7ff]);
+                words.push(' ');
+            }
+            words.pop();
+            if ElectrumVersion::of(&words) == Some(version) {
+                break Self::parse_unchecked(&words);
+            }
+        };
+        crate::wipe(&mut indices);
+        ret
+    }
+
+    /// Parse English words without checking the version, for shares.
+    ///
+    /// Words may be shortened to their unique prefixes, unknown words are
+    /// [Error::UnknownWord].
+    pub fn parse_unchecked(s: &str) -> Result<Self, SeedXorError> {
+        Ok(ElectrumMnemonic {
+            inner: Mnemonic::parse_in_normalized_without_checksum_check(Language::English, s)?,
+        })
+    }
+
+    /// The version of this seed, `None` for shares and anything else that isn't a valid
+    /// Electrum seed.
+    pub fn version(&self) -> Option<ElectrumVersion> {
+        ElectrumVersion::of(&self.inner.reveal())
+    }
+
+    /// Split into `n` shares which all XOR back to this seed, see [Mnemonic::splitn].
+    #[cfg(feature = "std")]
+    pub fn splitn(&self, n: usize) -> Result<Vec<Self>, SeedXorError> {
+        Ok(self
+            .inner
+            .clone()
+            .splitn_with_mode(n, RebuildMode::PreserveBytes)?
+            .into_iter()
+            .map(|inner| ElectrumMnemonic { inner })
+            .collect())
+    }
+
+    /// XOR `shares` together like [SeedXor::xor_all], but only accept a valid Electrum seed,
+    /// otherwise [Error::InvalidChecksum], as a wrong or missing share would give random words.
+    pub fn combine(shares: &[Self]) -> Result<Self, SeedXorError> {
+        let ret = Self::xor_all(shares)?;
+        match ret.version() {
+            Some(_) => Ok(ret),
+            None => Err(Error::InvalidChecksum.into()),
+        }
+    }
+
+    /// The words as a [Mnemonic], whose bip39 checksum is generally invalid.
+    pub fn mnemonic(&self) -> &Mnemonic {
+        &self.inner
+    }
+
+    /// The words separated by spaces, see [Mnemonic::reveal].
+    pub fn reveal(&self) -> String {
+        self.inner.reveal()
+    }
+}
+
+impl SeedXor for ElectrumMnemonic {
+    /// XOR every bit of every word, see [RebuildMode::PreserveBytes].
+    fn xor(&self, rhs: &Self) -> Self {
+        ElectrumMnemonic {
+            inner: self
+                .inner
+                .xor_with_mode(&rhs.inner, RebuildMode::PreserveBytes),
+        }
+    }
+}
+
+/// Parse a valid Electrum seed, a version other than [ElectrumVersion] is
+/// [Error::InvalidChecksum], use [ElectrumMnemonic::parse_unchecked] for shares.
+impl FromStr for ElectrumMnemonic {
+    type Err = SeedXorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let ret = Self::parse_unchecked(s)?;
+        match ret.version() {
+            Some(_) => Ok(ret),
+            None => Err(Error::InvalidChecksum.into()),
+        }
+    }
+}
+
+/// Same as the [Mnemonic] `Display`, so redacted with the `safe-default-display` feature.
+impl fmt::Display for ElectrumMnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+impl fmt::Debug for ElectrumMnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::electrum::*;
+
+    const STANDARD: &str =
+        "since sick check reward swamp mind board moral cross bounce mutual equip";
+    const SEGWIT: &str =
+        "ramp exotic resource icon sun addict equip sand leisure spare swing tobacco";
+
+    #[test]
+    fn versions() {
+        assert_eq!(
+            Some(ElectrumVersion::Standard),
+            ElectrumMnemonic::from_str(STANDARD).unwrap().version()
+        );
+        assert_eq!(
+            Some(ElectrumVersion::Segwit),
+            ElectrumMnemonic::from_str(SEGWIT).unwrap().version()
+        );
+        assert_eq!(
+            Some(ElectrumVersion::Segwit),
+            ElectrumMnemonic::from_str(
+                "wild father tree among universe such mobile favorite target dynamic credit identify"
+            )
+            .unwrap()
+            .version()
+        );
+
+        // a valid bip39 seed is not an Electrum seed
+        let bip39 = "silent toe meat possible chair blossom wait occur this worth option boy";
+        assert_eq!(
+            Err(Error::InvalidChecksum.into()),
+            ElectrumMnemonic::from_str(bip39)
+        );
+        assert_eq!(
+            None,
+            ElectrumMnemonic::parse_unchecked(bip39).unwrap().version()
+        );
+        assert_eq!(
+            Err(Error::UnknownWord(3).into()),
+            ElectrumMnemonic::from_str(
+                "since sick check rewardd swamp mind board moral cross bounce mutual equip"
+            )
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn split_and_combine() {
+        for seed in [STANDARD, SEGWIT] {
+            let seed = ElectrumMnemonic::from_str(seed).unwrap();
+            for n in 1..=5 {
+                let shares = seed.splitn(n).unwrap();
+                assert_eq!(n, shares.len());
+                let combined = ElectrumMnemonic::combine(&shares).unwrap();
+                assert_eq!(seed, combined);
+                assert_eq!(seed.version(), combined.version());
+                assert_eq!(seed.reveal(), combined.reveal());
+
+                // shares survive being written down and read back
+                let shares: Vec<_> = shares
+                    .iter()
+                    .map(|s| ElectrumMnemonic::parse_unchecked(&s.reveal()).unwrap())
+                    .collect();
+                assert_eq!(seed, ElectrumMnemonic::combine(&shares).unwrap());
+            }
+        }
+        assert_eq!(
+            Err(SeedXorError::EmptyInput),
+            ElectrumMnemonic::combine(&[])
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn generate() {
+        for version in [ElectrumVersion::Standard, ElectrumVersion::Segwit] {
+            let seed = ElectrumMnemonic::generate(version, 12).unwrap();
+            assert_eq!(12, seed.mnemonic().word_count());
+            assert_eq!(Some(version), seed.version());
+            assert_eq!(seed, ElectrumMnemonic::from_str(&seed.reveal()).unwrap());
+        }
+        assert_eq!(
+            Err(Error::BadWordCount(13).into()),
+            ElectrumMnemonic::generate(ElectrumVersion::Standard, 13)
+        );
+    }
+}
@@ -0,0 +1,120 @@
+//! Where the randomness for generated mnemonics and split shares comes from.
+
+use crate::{entropy_len_from_word_count, wipe, Error, Language, Mnemonic, SeedXorError};
+use alloc::vec;
+
+/// A source of cryptographically secure random bytes.
+pub trait EntropySource {
+    /// Fill all of `dest` with random bytes.
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), SeedXorError>;
+}
+
+/// The operating system's random number generator via [getrandom], used by [Mnemonic::generate_in].
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OsEntropy;
+
+#[cfg(feature = "std")]
+impl EntropySource for OsEntropy {
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), SeedXorError> {
+        Ok(getrandom::getrandom(dest)?)
+    }
+}
+
+impl<T: EntropySource + ?Sized> EntropySource for &mut T {
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), SeedXorError> {
+        (**self).fill_bytes(dest)
+    }
+}
+
+impl Mnemonic {
+    /// [Mnemonic::generate_in] with entropy from `source` instead of the operating system.
+    pub fn generate_in_with_source(
+        language: Language,
+        word_count: usize,
+        mut source: impl EntropySource,
+    ) -> Result<Self, SeedXorError> {
+        let len = entropy_len_from_word_count(word_count).ok_or(Error::BadWordCount(word_count))?;
+        let mut entropy = vec![0u8; len];
+        let ret = source
+            .fill_bytes(&mut entropy)
+            .and_then(|_| Ok(bip39::Mnemonic::from_entropy_in(language, &entropy)?.into()));
+        wipe(&mut entropy);
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::entropy::*;
+
+    struct Counter(u8);
+
+    impl EntropySource for Counter {
+        fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), SeedXorError> {
+            for b in dest {
+                *b = self.0;
+                self.0 = self.0.wrapping_add(1);
+            }
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn generate_in_with_source_works() {
+        let mut source = Counter(0);
+        let mnemonic =
+            Mnemonic::generate_in_with_source(Language::English, 12, &mut source).unwrap();
+        let (entropy, entropy_len) = mnemonic.to_entropy_array();
+        assert_eq!((0..16).collect::<Vec<u8>>(), entropy[0..entropy_len]);
+        let mnemonic =
+            Mnemonic::generate_in_with_source(Language::English, 12, &mut source).unwrap();
+        let (entropy, entropy_len) = mnemonic.to_entropy_array();
+        assert_eq!((16..32).collect::<Vec<u8>>(), entropy[0..entropy_len]);
+
+        assert_eq!(
+            Err(Error::BadWordCount(9).into()),
+            Mnemonic::generate_in_with_source(Language::English, 9, Counter(0))
+        );
+        assert_eq!(
+            24,
+            Mnemonic::generate_in_with_source(Language::English, 24, OsEntropy)
+                .unwrap()
+                .word_count()
+        );
+    }
+
+    #[test]
+    fn split_with_rng_is_deterministic() {
+        let entropy = |m: &Mnemonic| {
+            let (entropy, entropy_len) = m.to_entropy_array();
+            entropy[0..entropy_len].to_vec()
+        };
+        let counter = |start: u8| (start..start + 16).collect::<Vec<u8>>();
+        let xor = |a: &[u8], b: &[u8]| a.iter().zip(b).map(|(a, b)| a ^ b).collect::<Vec<u8>>();
+        let seed = Mnemonic::from([0xa5u8; 16]);
+
+        let [a, b] = seed.split_with_rng(&mut Counter(0)).unwrap();
+        assert_eq!(xor(&[0xa5; 16], &counter(0)), entropy(&a));
+        assert_eq!(counter(0), entropy(&b));
+        assert_eq!([a, b], seed.split_with_rng(&mut Counter(0)).unwrap());
+
+        let shares = seed.clone().splitn_with_rng(3, &mut Counter(0)).unwrap();
+        assert_eq!(
+            vec![
+                xor(&[0xa5; 16], &counter(0)),
+                xor(&counter(0), &counter(16)),
+                counter(16)
+            ],
+            shares.iter().map(entropy).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Err(SeedXorError::TooManyShares {
+                max: crate::MAX_SPLIT,
+                got: crate::MAX_SPLIT + 1
+            }),
+            seed.splitn_with_rng(crate::MAX_SPLIT + 1, &mut Counter(0))
+        );
+    }
+}
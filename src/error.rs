@@ -0,0 +1,200 @@
+//! The error type of everything in this crate that can fail.
+
+use core::fmt;
+
+/// Why a seedxor operation failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SeedXorError {
+    /// A mnemonic, word or word count was invalid.
+    Bip39(bip39::Error),
+    /// The source of randomness failed. Sources other than the operating system report their
+    /// own codes from [getrandom::Error::CUSTOM_START] up.
+    Rng(getrandom::Error),
+    /// There was nothing to work on, like no shares to combine.
+    EmptyInput,
+    /// Inputs that must line up did not, `got` items where `expected` were needed.
+    LengthMismatch { expected: usize, got: usize },
+    /// An operating system call other than for randomness failed with this `errno`.
+    Os(i32),
+    /// Shares did not recombine to the seed they were split from in any of `attempts` splits.
+    Unverified { attempts: usize },
+    /// Splitting into `got` shares is over the cap of `max`, see [crate::MAX_SPLIT].
+    TooManyShares { max: usize, got: usize },
+    /// A threshold split needs `2 <= k <= n <= 255`, `k` shares out of `n`.
+    InvalidThreshold { k: usize, n: usize },
+    /// The threshold share at this index has the `x` of an earlier share, or 0, that of the
+    /// secret itself.
+    DuplicateShare(usize),
+    /// A grid split needs `rows` and `cols` both at least 2 and both even or both odd.
+    InvalidGrid { rows: usize, cols: usize },
+    /// A bech32 string is malformed at this character index: a character outside the charset,
+    /// a missing `1` separator, too short a checksum or mixed case, reported at 0.
+    InvalidBech32(usize),
+    /// A bech32 string has a human-readable prefix other than the one expected.
+    UnexpectedHrp,
+    /// A derivation path part at this index, counting the leading `m` as 0, is not a child
+    /// number.
+    InvalidPath(usize),
+    /// A derivation path purpose other than BIP44, BIP49 or BIP84.
+    UnsupportedPurpose(u32),
+    /// The character at this index is not in the base58 alphabet.
+    InvalidBase58(usize),
+    /// An extended public key is not 78 bytes with the version of an `xpub`, `ypub` or `zpub`.
+    InvalidXpub,
+    /// A derived BIP32 key is zero or not below the curve order, which BIP32 says to skip.
+    InvalidKey,
+    /// An `index`, counted from 0, that is not below `len`, like a word index past the end of a
+    /// mnemonic of `len` words or a BIP85 index of 2^31 or more, which can't be hardened.
+    IndexOutOfRange { index: usize, len: usize },
+    /// The same word position, counted from 0, was fixed more than once.
+    DuplicatePosition(usize),
+    /// The dice roll at this index is not from 1 to 6.
+    InvalidRoll(usize),
+    /// The short form of the word at this position expands to a different word.
+    AmbiguousShortForm(usize),
+    /// The passphrase has a non-ASCII character at this position, counted in characters, which
+    /// would need unicode normalization.
+    NonAsciiPassphrase(usize),
+    /// A `ur:crypto-seed` is malformed, another UR type or missing the entropy.
+    InvalidUr,
+    /// The pair of letters at this index, counted in pairs, is not a byteword.
+    InvalidByteword(usize),
+    /// An image is not a PNG written by [crate::Mnemonic::to_bit_png].
+    InvalidPng,
+    /// The character at this index is not a hex digit.
+    InvalidHex(usize),
+    /// This many hex digits are an odd number or not 32 to 64 in steps of 8, the entropy of a
+    /// valid word count.
+    InvalidHexLength(usize),
+}
+
+impl From<bip39::Error> for SeedXorError {
+    fn from(e: bip39::Error) -> Self {
+        SeedXorError::Bip39(e)
+    }
+}
+
+impl From<getrandom::Error> for SeedXorError {
+    fn from(e: getrandom::Error) -> Self {
+        SeedXorError::Rng(e)
+    }
+}
+
+impl fmt::Display for SeedXorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SeedXorError::Bip39(e) => write!(f, "{e}"),
+            SeedXorError::Rng(e) => write!(f, "cannot get randomness: {e}"),
+            SeedXorError::EmptyInput => write!(f, "nothing to work on"),
+            SeedXorError::LengthMismatch { expected, got } => {
+                write!(f, "expected {expected} items but got {got}")
+            }
+            #[cfg(feature = "std")]
+            SeedXorError::Os(errno) => {
+                write!(f, "{}", std::io::Error::from_raw_os_error(*errno))
+            }
+            #[cfg(not(feature = "std"))]
+            SeedXorError::Os(errno) => write!(f, "os error {errno}"),
+            SeedXorError::Unverified { attempts } => {
+                write!(
+                    f,
+                    "shares did not recombine to the seed in {attempts} attempts"
+                )
+            }
+            SeedXorError::TooManyShares { max, got } => {
+                write!(f, "{got} shares is more than the most of {max}")
+            }
+            SeedXorError::InvalidThreshold { k, n } => {
+                write!(f, "cannot split {k} of {n}, needs 2 <= k <= n <= 255")
+            }
+            SeedXorError::InvalidGrid { rows, cols } => write!(
+                f,
+                "cannot split into a {rows} by {cols} grid, needs both at least 2 and both even or both odd"
+            ),
+            SeedXorError::DuplicateShare(i) => {
+                write!(f, "share {i} has the x of another share or 0")
+            }
+            SeedXorError::InvalidUr => write!(f, "not a ur:crypto-seed"),
+            SeedXorError::InvalidByteword(i) => write!(f, "letter pair {i} is not a byteword"),
+            SeedXorError::InvalidPng => write!(f, "not a PNG of seed bits"),
+            SeedXorError::InvalidHex(i) => write!(f, "character {i} is not a hex digit"),
+            SeedXorError::InvalidHexLength(len) => {
+                write!(f, "{len} hex digits is not 32, 40, 48, 56 or 64")
+            }
+            SeedXorError::InvalidBech32(i) => write!(f, "invalid bech32 at character {i}"),
+            SeedXorError::UnexpectedHrp => write!(f, "bech32 prefix is not the expected one"),
+            SeedXorError::InvalidPath(i) => write!(f, "invalid path at part {i}"),
+            SeedXorError::UnsupportedPurpose(purpose) => {
+                write!(f, "unsupported purpose {purpose}', supported: 44', 49', 84'")
+            }
+            SeedXorError::InvalidBase58(i) => write!(f, "character {i} is not base58"),
+            SeedXorError::InvalidXpub => write!(f, "not an xpub, ypub or zpub"),
+            SeedXorError::InvalidKey => write!(f, "invalid BIP32 key"),
+            SeedXorError::IndexOutOfRange { index, len } => {
+                write!(f, "index {index} is out of range, needs less than {len}")
+            }
+            SeedXorError::DuplicatePosition(i) => write!(f, "word position {i} is fixed twice"),
+            SeedXorError::InvalidRoll(i) => write!(f, "roll {i} is not from 1 to 6"),
+            SeedXorError::AmbiguousShortForm(i) => {
+                write!(f, "short form of word {i} expands to a different word")
+            }
+            SeedXorError::NonAsciiPassphrase(i) => {
+                write!(f, "passphrase has a non-ASCII character at position {i}, normalize it to NFKD first")
+            }
+        }
+    }
+}
+
+impl core::error::Error for SeedXorError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            bip39::Error::BadWordCount(9).to_string(),
+            SeedXorError::from(bip39::Error::BadWordCount(9)).to_string()
+        );
+        assert_eq!("nothing to work on", SeedXorError::EmptyInput.to_string());
+        assert_eq!(
+            "expected 3 items but got 2",
+            SeedXorError::LengthMismatch {
+                expected: 3,
+                got: 2
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "shares did not recombine to the seed in 4 attempts",
+            SeedXorError::Unverified { attempts: 4 }.to_string()
+        );
+        assert_eq!(
+            "5 shares is more than the most of 4",
+            SeedXorError::TooManyShares { max: 4, got: 5 }.to_string()
+        );
+        assert_eq!(
+            "cannot split 4 of 3, needs 2 <= k <= n <= 255",
+            SeedXorError::InvalidThreshold { k: 4, n: 3 }.to_string()
+        );
+        assert_eq!(
+            "31 hex digits is not 32, 40, 48, 56 or 64",
+            SeedXorError::InvalidHexLength(31).to_string()
+        );
+        assert_eq!(
+            "character 1 is not base58",
+            SeedXorError::InvalidBase58(1).to_string()
+        );
+        assert_eq!(
+            "index 24 is out of range, needs less than 24",
+            SeedXorError::IndexOutOfRange { index: 24, len: 24 }.to_string()
+        );
+        let rng = getrandom::Error::from(
+            std::num::NonZeroU32::new(getrandom::Error::CUSTOM_START).unwrap(),
+        );
+        assert!(SeedXorError::from(rng)
+            .to_string()
+            .starts_with("cannot get randomness"));
+    }
+}
@@ -0,0 +1,279 @@
+//! C functions to combine, split and expand seeds from other languages, such as Go through
+//! cgo, built as a shared library with
+//! `cargo rustc --release --lib --features ffi --crate-type cdylib`.
+//!
+//! ```c
+//! int32_t seedxor_combine(const char *const *parts, size_t len, char *out, size_t out_len);
+//! int32_t seedxor_split(const char *seed, size_t n, char *out, size_t out_len);
+//! int32_t seedxor_expand(const char *words, char *out, size_t out_len);
+//! ```
+//!
+//! The caller owns all memory: every input is a nul terminated UTF-8 string that is only read
+//! during the call, and the result is written as a nul terminated string into the `out_len`
+//! bytes at `out`, nothing is allocated for the caller or kept after returning. On anything
+//! but [SEEDXOR_OK] an `out_len` of at least 1 gets an empty string. Results are seeds, so
+//! wipe `out` when done with it.
+
+use crate::{expand_words, wipe, Mnemonic, SeedXor, SeedXorError};
+use alloc::{string::String, vec::Vec};
+use core::{ffi::c_char, ffi::CStr, ptr, slice, str::FromStr};
+
+/// Success, `out` holds the result.
+pub const SEEDXOR_OK: i32 = 0;
+/// A pointer argument was null.
+pub const SEEDXOR_ERR_NULL: i32 = -1;
+/// A string argument was not UTF-8.
+pub const SEEDXOR_ERR_UTF8: i32 = -2;
+/// A seed, word or count was invalid, or there was nothing to combine.
+pub const SEEDXOR_ERR_INVALID: i32 = -3;
+/// The result and its nul didn't fit in `out_len` bytes.
+pub const SEEDXOR_ERR_BUFFER: i32 = -4;
+/// No randomness could be had to split with.
+pub const SEEDXOR_ERR_RNG: i32 = -5;
+
+fn status(e: SeedXorError) -> i32 {
+    match e {
+        SeedXorError::Rng(_) | SeedXorError::Os(_) => SEEDXOR_ERR_RNG,
+        _ => SEEDXOR_ERR_INVALID,
+    }
+}
+
+/// Borrow the nul terminated UTF-8 string at `s`.
+///
+/// # Safety
+///
+/// `s` must be null or point to a nul terminated string that outlives `'a`.
+unsafe fn borrow_str<'a>(s: *const c_char) -> Result<&'a str, i32> {
+    if s.is_null() {
+        return Err(SEEDXOR_ERR_NULL);
+    }
+    // SAFETY: s is not null and the caller guarantees it is nul terminated.
+    unsafe { CStr::from_ptr(s) }
+        .to_str()
+        .map_err(|_| SEEDXOR_ERR_UTF8)
+}
+
+/// Write `text` and a nul to `out`, wiping `text` either way.
+///
+/// # Safety
+///
+/// `out` must be valid for writes of `out_len` bytes.
+unsafe fn write_out(text: String, out: *mut c_char, out_len: usize) -> i32 {
+    let mut text = text.into_bytes();
+    let ret = if text.len() < out_len {
+        // SAFETY: out has room for text and the nul, and can't overlap text which we own.
+        unsafe {
+            ptr::copy_nonoverlapping(text.as_ptr(), out as *mut u8, text.len());
+            *out.add(text.len()) = 0;
+        }
+        SEEDXOR_OK
+    } else {
+        SEEDXOR_ERR_BUFFER
+    };
+    wipe(&mut text);
+    ret
+}
+
+/// Run `f` and write its result to `out`, or an empty string on failure.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes of `out_len` bytes.
+unsafe fn run(out: *mut c_char, out_len: usize, f: impl FnOnce() -> Result<String, i32>) -> i32 {
+    if out.is_null() {
+        return SEEDXOR_ERR_NULL;
+    }
+    let ret = match f() {
+        // SAFETY: the caller guarantees out is valid for out_len bytes.
+        Ok(text) => unsafe { write_out(text, out, out_len) },
+        Err(e) => e,
+    };
+    if ret != SEEDXOR_OK && out_len > 0 {
+        // SAFETY: out is valid for at least 1 byte.
+        unsafe { *out = 0 };
+    }
+    ret
+}
+
+/// XOR the `len` seeds at `parts` together, like `seedxor --combine`, writing the words of
+/// the result to `out`.
+///
+/// # Safety
+///
+/// `parts` must point to `len` pointers that are each null or a nul terminated string, and
+/// `out` must be null or valid for writes of `out_len` bytes, see the
+/// [module docs](crate::ffi).
+#[no_mangle]
+pub unsafe extern "C" fn seedxor_combine(
+    parts: *const *const c_char,
+    len: usize,
+    out: *mut c_char,
+    out_len: usize,
+) -> i32 {
+    // SAFETY: forwarded from the caller.
+    unsafe {
+        run(out, out_len, || {
+            if parts.is_null() {
+                return Err(SEEDXOR_ERR_NULL);
+            }
+            let parts = slice::from_raw_parts(parts, len)
+                .iter()
+                .map(|part| Mnemonic::from_str(borrow_str(*part)?).map_err(|_| SEEDXOR_ERR_INVALID))
+                .collect::<Result<Vec<_>, i32>>()?;
+            Ok(Mnemonic::xor_all(&parts).map_err(status)?.reveal())
+        })
+    }
+}
+
+/// Split `seed` into `n` shares, like `seedxor --split`, writing them to `out` one per line.
+///
+/// # Safety
+///
+/// `seed` must be null or a nul terminated string, and `out` must be null or valid for
+/// writes of `out_len` bytes, see the [module docs](crate::ffi).
+#[no_mangle]
+pub unsafe extern "C" fn seedxor_split(
+    seed: *const c_char,
+    n: usize,
+    out: *mut c_char,
+    out_len: usize,
+) -> i32 {
+    // SAFETY: forwarded from the caller.
+    unsafe {
+        run(out, out_len, || {
+            let seed = Mnemonic::from_str(borrow_str(seed)?).map_err(|_| SEEDXOR_ERR_INVALID)?;
+            let shares = seed.splitn(n).map_err(status)?;
+            let words: Vec<String> = shares.iter().map(Mnemonic::reveal).collect();
+            Ok(words.join("\n"))
+        })
+    }
+}
+
+/// Expand `words` shortened to unique prefixes back into whole words, like
+/// [expand_words], writing them to `out`.
+///
+/// # Safety
+///
+/// `words` must be null or a nul terminated string, and `out` must be null or valid for
+/// writes of `out_len` bytes, see the [module docs](crate::ffi).
+#[no_mangle]
+pub unsafe extern "C" fn seedxor_expand(
+    words: *const c_char,
+    out: *mut c_char,
+    out_len: usize,
+) -> i32 {
+    // SAFETY: forwarded from the caller.
+    unsafe {
+        run(out, out_len, || {
+            expand_words(borrow_str(words)?).map_err(status)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ffi::*;
+    use std::ffi::CString;
+
+    const SEED: &str = "silent toe meat possible chair blossom wait occur this worth option boy";
+
+    fn out_str(out: &[c_char]) -> String {
+        // SAFETY: out is nul terminated by every function on success and failure.
+        unsafe { CStr::from_ptr(out.as_ptr()) }
+            .to_str()
+            .unwrap()
+            .to_owned()
+    }
+
+    #[test]
+    fn split_and_combine_roundtrip() {
+        let seed = CString::new(SEED).unwrap();
+        let mut out = [0 as c_char; 1024];
+        // SAFETY: seed is nul terminated and out is valid for its length.
+        let ret = unsafe { seedxor_split(seed.as_ptr(), 3, out.as_mut_ptr(), out.len()) };
+        assert_eq!(SEEDXOR_OK, ret);
+        let shares: Vec<CString> = out_str(&out)
+            .lines()
+            .map(|l| CString::new(l).unwrap())
+            .collect();
+        assert_eq!(3, shares.len());
+
+        let parts: Vec<*const c_char> = shares.iter().map(|s| s.as_ptr()).collect();
+        let mut out = [0 as c_char; 256];
+        // SAFETY: parts holds parts.len() nul terminated strings and out is valid.
+        let ret =
+            unsafe { seedxor_combine(parts.as_ptr(), parts.len(), out.as_mut_ptr(), out.len()) };
+        assert_eq!(SEEDXOR_OK, ret);
+        assert_eq!(SEED, out_str(&out));
+    }
+
+    #[test]
+    fn expand() {
+        let short =
+            CString::new("sile toe meat poss chai blos wait occu this wort opti boy").unwrap();
+        let mut out = [0 as c_char; 256];
+        // SAFETY: short is nul terminated and out is valid for its length.
+        let ret = unsafe { seedxor_expand(short.as_ptr(), out.as_mut_ptr(), out.len()) };
+        assert_eq!(SEEDXOR_OK, ret);
+        assert_eq!(SEED, out_str(&out));
+    }
+
+    #[test]
+    fn errors() {
+        let seed = CString::new(SEED).unwrap();
+        let mut out = [b'x' as c_char; 16];
+        // SAFETY: every pointer is null, nul terminated or valid for its length.
+        unsafe {
+            assert_eq!(
+                SEEDXOR_ERR_BUFFER,
+                seedxor_expand(seed.as_ptr(), out.as_mut_ptr(), out.len())
+            );
+            assert_eq!("", out_str(&out));
+            assert_eq!(
+                SEEDXOR_ERR_BUFFER,
+                seedxor_expand(seed.as_ptr(), out.as_mut_ptr(), SEED.len())
+            );
+            let mut out = [0 as c_char; 256];
+            assert_eq!(
+                SEEDXOR_OK,
+                seedxor_expand(seed.as_ptr(), out.as_mut_ptr(), SEED.len() + 1)
+            );
+            assert_eq!(
+                SEEDXOR_ERR_NULL,
+                seedxor_expand(ptr::null(), out.as_mut_ptr(), out.len())
+            );
+            assert_eq!(
+                SEEDXOR_ERR_NULL,
+                seedxor_expand(seed.as_ptr(), ptr::null_mut(), 0)
+            );
+            let bad = CString::new("silent toe meatt").unwrap();
+            assert_eq!(
+                SEEDXOR_ERR_INVALID,
+                seedxor_expand(bad.as_ptr(), out.as_mut_ptr(), out.len())
+            );
+            let bad = [0xffu8 as c_char, 0];
+            assert_eq!(
+                SEEDXOR_ERR_UTF8,
+                seedxor_expand(bad.as_ptr(), out.as_mut_ptr(), out.len())
+            );
+            assert_eq!(
+                SEEDXOR_ERR_INVALID,
+                seedxor_combine([].as_ptr(), 0, out.as_mut_ptr(), out.len())
+            );
+            let parts = [seed.as_ptr(), ptr::null()];
+            assert_eq!(
+                SEEDXOR_ERR_NULL,
+                seedxor_combine(parts.as_ptr(), 2, out.as_mut_ptr(), out.len())
+            );
+            assert_eq!(
+                SEEDXOR_ERR_INVALID,
+                seedxor_split(
+                    seed.as_ptr(),
+                    crate::MAX_SPLIT + 1,
+                    out.as_mut_ptr(),
+                    out.len()
+                )
+            );
+        }
+    }
+}
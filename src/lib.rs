@@ -33,31 +33,103 @@
 //! let a = Mnemonic::from_str(a_str).unwrap();
 //! let split = a.splitn(3).unwrap();
 //! let recombined_a = Mnemonic::xor_all(&split).unwrap();
-//! assert_eq!(a_str, recombined_a.to_string());
+//! assert_eq!(a_str, recombined_a.reveal());
 //! ```
 //!
+//! ## no_std
+//!
+//! Without the default `std` feature the crate is `no_std` with `alloc`, for firmware and the
+//! like. Everything that draws from [OsEntropy] is then gone, use the `*_with_rng` methods
+//! with an [EntropySource] of the device instead, such as [Mnemonic::generate_in_with_source]
+//! and [Mnemonic::splitn_with_rng].
+//!
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "bitcoin")]
+pub mod address;
+mod bech32;
+#[cfg(feature = "bip32")]
+mod bip32;
+#[cfg(feature = "bip85")]
+mod bip85;
+mod coins;
+mod diagnose;
+mod dice;
+mod electrum;
+mod entropy;
+mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(all(feature = "mlock", unix))]
+pub mod mlock;
+#[cfg(all(feature = "pkcs11", unix))]
+mod pkcs11;
+pub mod qr;
+#[cfg(feature = "bip32")]
+mod secp256k1;
+mod secret;
+mod seedqr;
+pub mod shamir;
+mod timelock;
+mod unscramble;
+mod ur;
+pub mod visual;
+mod wordlist;
+mod xof;
+
+use alloc::collections::BTreeSet;
+use alloc::{borrow::ToOwned, format, string::String, vec, vec::Vec};
 pub use bip39::{Error, Language};
-use std::{
+use bitcoin_hashes::{sha256, Hash};
+pub use coins::CoinSource;
+use core::{
     fmt,
     fmt::Display,
     ops::{BitXor, BitXorAssign, Deref, DerefMut},
     str::FromStr,
 };
+pub use diagnose::Diagnosis;
+pub use dice::{min_dice_rolls, DiceSource};
+pub use electrum::{ElectrumMnemonic, ElectrumVersion};
+pub use entropy::EntropySource;
+#[cfg(feature = "std")]
+pub use entropy::OsEntropy;
+pub use error::SeedXorError;
+#[cfg(all(feature = "mlock", unix))]
+pub use mlock::LockedMnemonic;
+#[cfg(all(feature = "pkcs11", unix))]
+pub use pkcs11::Pkcs11Source;
+pub use secret::Secret;
+pub use shamir::{combine_threshold, combine_threshold_report, Share};
+#[cfg(feature = "std")]
+use std::sync::{
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+    OnceLock,
+};
+#[cfg(feature = "std")]
+pub use timelock::combine_time_locked;
+pub use timelock::{combine_time_locked_at, TimeLockError, TimeLockedShare};
+pub use unscramble::unscramble_with_anchors;
+pub use visual::combine_bit_pngs;
+pub use wordlist::Wordlist;
 
 /// Trait for a `XOR`.
 pub trait SeedXor {
     /// XOR two values without consuming them.
     fn xor(&self, rhs: &Self) -> Self;
 
-    fn xor_all(slice: &[Self]) -> Option<Self>
+    /// XOR every value in `slice` together, an empty slice is [SeedXorError::EmptyInput].
+    fn xor_all(slice: &[Self]) -> Result<Self, SeedXorError>
     where
         Self: Sized + Clone,
     {
-        let first = slice.get(0)?;
+        let first = slice.first().ok_or(SeedXorError::EmptyInput)?;
         // expensive clone :)
         //let first = first.xor(first).xor(first);
         let first = first.clone();
-        Some(slice.iter().skip(1).fold(first, |x, y| x.xor(y)))
+        Ok(slice.iter().skip(1).fold(first, |x, y| x.xor(y)))
     }
 }
 
@@ -65,30 +137,119 @@ impl SeedXor for bip39::Mnemonic {
     /// XOR self with another [bip39::Mnemonic] without consuming it or itself.
     fn xor(&self, rhs: &Self) -> Self {
         let (mut entropy, entropy_len) = self.to_entropy_array();
-        let (xor_values, xor_values_len) = rhs.to_entropy_array();
-        let entropy = &mut entropy[0..entropy_len];
-        let xor_values = &xor_values[0..xor_values_len];
+        let (mut xor_values, xor_values_len) = rhs.to_entropy_array();
+        let both_len = entropy_len.min(xor_values_len);
 
         // XOR each Byte
-        entropy
+        entropy[0..both_len]
             .iter_mut()
-            .zip(xor_values.iter())
+            .zip(xor_values[0..both_len].iter())
             .for_each(|(a, b)| *a ^= b);
 
         // Extend entropy with values of xor_values if it has a shorter entropy length.
-        if entropy.len() < xor_values.len() {
-            let mut entropy = entropy.to_vec();
-            entropy.extend(xor_values.iter().skip(entropy.len()));
+        if entropy_len < xor_values_len {
+            entropy[entropy_len..xor_values_len]
+                .copy_from_slice(&xor_values[entropy_len..xor_values_len]);
+        }
 
-            bip39::Mnemonic::from_entropy(&entropy).unwrap()
-        } else {
-            // We unwrap here because entropy has either as many Bytes
-            // as self or rhs and both are valid mnemonics.
-            bip39::Mnemonic::from_entropy(&entropy).unwrap()
+        // We unwrap here because entropy has either as many Bytes
+        // as self or rhs and both are valid mnemonics.
+        let ret =
+            bip39::Mnemonic::from_entropy(&entropy[0..entropy_len.max(xor_values_len)]).unwrap();
+        wipe(&mut entropy);
+        wipe(&mut xor_values);
+        ret
+    }
+}
+
+/// Overwrite `buf` with zeros in a way the compiler can't optimize away, with the `zeroize`
+/// feature, and do nothing without it.
+#[inline]
+pub(crate) fn wipe(buf: &mut [u8]) {
+    #[cfg(feature = "zeroize")]
+    {
+        for b in buf.iter_mut() {
+            // SAFETY: b is a valid, aligned, exclusive reference.
+            unsafe { core::ptr::write_volatile(b, 0) };
         }
+        core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+    }
+    #[cfg(not(feature = "zeroize"))]
+    let _ = buf;
+}
+
+/// Overwrite a secret in place, the shape of the trait of the same name in the `zeroize` crate,
+/// which can't be a dependency here, so code written against it carries over.
+#[cfg(feature = "zeroize")]
+pub trait Zeroize {
+    /// Overwrite the secret held, see [Mnemonic::zeroize].
+    fn zeroize(&mut self);
+}
+
+/// Marks types that [Zeroize] themselves when dropped, like the trait of the same name in the
+/// `zeroize` crate.
+#[cfg(feature = "zeroize")]
+pub trait ZeroizeOnDrop {}
+
+#[cfg(feature = "zeroize")]
+impl Zeroize for [u8] {
+    fn zeroize(&mut self) {
+        wipe(self);
+    }
+}
+
+/// Whether `a` and `b`, of equal length, are equal, in time that depends only on the length
+/// rather than on how many leading bytes match.
+#[inline]
+pub(crate) fn ct_eq_bytes(a: &[u8], b: &[u8]) -> bool {
+    debug_assert_eq!(a.len(), b.len());
+    let diff = a.iter().zip(b).fold(0u8, |acc, (a, b)| acc | (a ^ b));
+    core::hint::black_box(diff) == 0
+}
+
+/// XOR the 11 bit word indices of `a` and `b`, checksum bits included, taking on the surplus
+/// words of the longer one, in the language of `a`.
+fn xor_words(a: &bip39::Mnemonic, b: &bip39::Mnemonic) -> bip39::Mnemonic {
+    let (a_lang, b_lang) = (a.language(), b.language());
+    let mut a = a
+        .word_iter()
+        .map(|w| a_lang.find_word(w).expect("valid word"));
+    let mut b = b
+        .word_iter()
+        .map(|w| b_lang.find_word(w).expect("valid word"));
+    let list = a_lang.word_list();
+    let mut words = String::new();
+    loop {
+        let idx = match (a.next(), b.next()) {
+            (Some(a), Some(b)) => a ^ b,
+            (Some(x), None) | (None, Some(x)) => x,
+            (None, None) => break,
+        };
+        words.push_str(list[idx as usize]);
+        words.push(' ');
     }
+    words.pop();
+    // We unwrap here because every word comes from the wordlist and the word count is
+    // that of self or rhs, which are both valid mnemonics.
+    bip39::Mnemonic::parse_in_normalized_without_checksum_check(a_lang, &words).unwrap()
+}
+
+/// How a [Mnemonic] is rebuilt after XORing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RebuildMode {
+    /// XOR only the entropy and recompute the bip39 checksum, the result is always a valid
+    /// bip39 mnemonic. This is what [SeedXor::xor] and the `^` operator do.
+    #[default]
+    Recompute,
+    /// XOR every bit of every word, checksum bits included, so seeds with arbitrary last words
+    /// (like electrum seeds) survive a split and combine untouched.
+    /// Shares made this way generally do not have a valid bip39 checksum.
+    PreserveBytes,
 }
 
+/// Most shares [Mnemonic::splitn] will split into.
+pub const MAX_SPLIT: usize = 10_000;
+
 /// Wrapper for a [bip39::Mnemonic] for the implementation of `^` and `^=` operators.
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Mnemonic {
@@ -97,40 +258,544 @@ pub struct Mnemonic {
 }
 
 impl Mnemonic {
-    pub fn split(&self) -> Result<[Self; 2], Error> {
-        let random = Self::generate_in(self.language(), self.word_count())?;
-        let calc = self.xor(&random);
+    #[cfg(feature = "std")]
+    pub fn split(&self) -> Result<[Self; 2], SeedXorError> {
+        self.split_with_mode(RebuildMode::Recompute)
+    }
+
+    /// Split into `n` shares which all XOR back to this mnemonic.
+    ///
+    /// Every share is built from entropy with its checksum recomputed, so each one is on its
+    /// own a fully valid bip39 mnemonic, indistinguishable from any other wallet seed. Only
+    /// [RebuildMode::PreserveBytes] gives up on that.
+    ///
+    /// More than [MAX_SPLIT] shares is almost certainly a typo so is refused with
+    /// [SeedXorError::TooManyShares] instead of allocating them, see [Mnemonic::splitn_with_max].
+    /// No shares at all is [SeedXorError::EmptyInput].
+    #[cfg(feature = "std")]
+    pub fn splitn(self, n: usize) -> Result<Vec<Self>, SeedXorError> {
+        self.splitn_with_mode(n, RebuildMode::Recompute)
+    }
+
+    /// Split into a `rows` by `cols` grid of shares where every full row, and every full column,
+    /// recombines to this mnemonic with [SeedXor::xor_all].
+    ///
+    /// The top left `rows - 1` by `cols - 1` shares are random, the last share of each row and
+    /// column is the correction making it XOR to the seed, and the corner share satisfies both
+    /// the last row and last column. For the corner to satisfy both, `rows` and `cols` must
+    /// both be even or both be odd, and at least 2, otherwise [SeedXorError::InvalidGrid] is
+    /// returned.
+    #[cfg(feature = "std")]
+    pub fn split_2d(&self, rows: usize, cols: usize) -> Result<Vec<Vec<Self>>, SeedXorError> {
+        if rows < 2 || cols < 2 || rows % 2 != cols % 2 {
+            return Err(SeedXorError::InvalidGrid { rows, cols });
+        }
+        let mut grid: Vec<Vec<Self>> = Vec::with_capacity(rows);
+        for _ in 0..rows - 1 {
+            let mut row = Vec::with_capacity(cols);
+            let mut correction = self.clone();
+            for _ in 0..cols - 1 {
+                let random = Self::generate_in(self.language(), self.word_count())?;
+                correction ^= random.clone();
+                row.push(random);
+            }
+            row.push(correction);
+            grid.push(row);
+        }
+        let last_row = (0..cols)
+            .map(|c| grid.iter().fold(self.clone(), |x, row| x.xor(&row[c])))
+            .collect();
+        grid.push(last_row);
+        Ok(grid)
+    }
+
+    /// Split into one share per label, in order, see [Mnemonic::splitn].
+    ///
+    /// Fewer than 2 labels would not split anything so are rejected with
+    /// [SeedXorError::LengthMismatch] of the 2 needed.
+    #[cfg(feature = "std")]
+    pub fn split_with_labels(&self, labels: &[&str]) -> Result<Vec<LabeledShare>, SeedXorError> {
+        if labels.len() < 2 {
+            return Err(SeedXorError::LengthMismatch {
+                expected: 2,
+                got: labels.len(),
+            });
+        }
+        Ok(self
+            .clone()
+            .splitn(labels.len())?
+            .into_iter()
+            .zip(labels)
+            .map(|(share, label)| LabeledShare {
+                label: (*label).to_owned(),
+                share,
+            })
+            .collect())
+    }
+
+    /// [SeedXor::xor] rebuilding the result according to `mode`.
+    pub fn xor_with_mode(&self, rhs: &Self, mode: RebuildMode) -> Self {
+        match mode {
+            RebuildMode::Recompute => self.xor(rhs),
+            RebuildMode::PreserveBytes => xor_words(&self.inner, &rhs.inner).into(),
+        }
+    }
+
+    /// [SeedXor::xor] of mnemonics with the same word count only, anything else is
+    /// [SeedXorError::LengthMismatch] of the word counts rather than extended to the longer.
+    pub fn xor_strict(&self, rhs: &Self) -> Result<Self, SeedXorError> {
+        Self::xor_all_strict(&[self.clone(), rhs.clone()])
+    }
+
+    /// [SeedXor::xor_all] of mnemonics with the same word count only, a share of another word
+    /// count than the first is [SeedXorError::LengthMismatch], like a mistyped one would be.
+    pub fn xor_all_strict(slice: &[Self]) -> Result<Self, SeedXorError> {
+        let expected = slice.first().ok_or(SeedXorError::EmptyInput)?.word_count();
+        if let Some(share) = slice.iter().find(|s| s.word_count() != expected) {
+            return Err(SeedXorError::LengthMismatch {
+                expected,
+                got: share.word_count(),
+            });
+        }
+        Self::xor_all(slice)
+    }
+
+    /// [SeedXor::xor_all] rebuilding the result according to `mode`, an empty slice is
+    /// [SeedXorError::EmptyInput].
+    pub fn xor_all_with_mode(slice: &[Self], mode: RebuildMode) -> Result<Self, SeedXorError> {
+        let first = slice.first().ok_or(SeedXorError::EmptyInput)?.clone();
+        Ok(slice
+            .iter()
+            .skip(1)
+            .fold(first, |x, y| x.xor_with_mode(y, mode)))
+    }
+
+    /// XOR `rhs` into this mnemonic, like `^=` but without taking or cloning `rhs`.
+    ///
+    /// Each call still builds one new mnemonic, to combine many shares with a single one built
+    /// at the end use [Mnemonic::xor_all_fast].
+    pub fn xor_into(&mut self, rhs: &Self) {
+        *self = self.xor(rhs);
+    }
+
+    /// [SeedXor::xor_all] of the shares of `iter` without collecting them into a slice first, no
+    /// shares is [SeedXorError::EmptyInput].
+    pub fn xor_iter<I: IntoIterator<Item = Mnemonic>>(iter: I) -> Result<Self, SeedXorError> {
+        let mut iter = iter.into_iter();
+        let first = iter.next().ok_or(SeedXorError::EmptyInput)?;
+        Ok(iter.fold(first, |x, y| x ^ y))
+    }
+
+    /// Same result as [SeedXor::xor_all] but accumulates the entropy of every share into a
+    /// single buffer and only builds one [Mnemonic] at the end, instead of one per share.
+    pub fn xor_all_fast(shares: &[Self]) -> Result<Self, SeedXorError> {
+        if shares.is_empty() {
+            return Err(SeedXorError::EmptyInput);
+        }
+        let mut entropy = [0u8; 33];
+        let mut entropy_len = 0;
+        for share in shares {
+            let (mut share, share_len) = share.to_entropy_array();
+            entropy_len = entropy_len.max(share_len);
+            entropy
+                .iter_mut()
+                .zip(share[0..share_len].iter())
+                .for_each(|(a, b)| *a ^= b);
+            wipe(&mut share);
+        }
+        let ret = Self::from_entropy(&entropy[0..entropy_len]);
+        wipe(&mut entropy);
+        ret
+    }
+
+    /// [Mnemonic::split] rebuilding the shares according to `mode`.
+    #[cfg(feature = "std")]
+    pub fn split_with_mode(&self, mode: RebuildMode) -> Result<[Self; 2], SeedXorError> {
+        self.split_with_mode_rng(mode, &mut OsEntropy)
+    }
+
+    /// [Mnemonic::split] with the random share drawn from `rng` instead of the operating
+    /// system, for reproducible tests or entropy from dice or a hardware RNG.
+    pub fn split_with_rng<R: EntropySource + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> Result<[Self; 2], SeedXorError> {
+        self.split_with_mode_rng(RebuildMode::Recompute, rng)
+    }
+
+    /// [Mnemonic::splitn] with the random shares drawn from `rng`, see [Mnemonic::split_with_rng].
+    pub fn splitn_with_rng<R: EntropySource + ?Sized>(
+        self,
+        n: usize,
+        rng: &mut R,
+    ) -> Result<Vec<Self>, SeedXorError> {
+        if n > MAX_SPLIT {
+            return Err(SeedXorError::TooManyShares {
+                max: MAX_SPLIT,
+                got: n,
+            });
+        }
+        self.splitn_uncapped(n, RebuildMode::Recompute, rng)
+    }
+
+    fn split_with_mode_rng<R: EntropySource + ?Sized>(
+        &self,
+        mode: RebuildMode,
+        rng: &mut R,
+    ) -> Result<[Self; 2], SeedXorError> {
+        let random = Self::generate_in_with_source(self.language(), self.word_count(), rng)?;
+        let calc = self.xor_with_mode(&random, mode);
         Ok([calc, random])
     }
 
-    pub fn splitn(self, n: usize) -> Result<Vec<Self>, Error> {
+    /// [Mnemonic::splitn] rebuilding the shares according to `mode`.
+    #[cfg(feature = "std")]
+    pub fn splitn_with_mode(self, n: usize, mode: RebuildMode) -> Result<Vec<Self>, SeedXorError> {
+        if n > MAX_SPLIT {
+            return Err(SeedXorError::TooManyShares {
+                max: MAX_SPLIT,
+                got: n,
+            });
+        }
+        self.splitn_uncapped(n, mode, &mut OsEntropy)
+    }
+
+    /// [Mnemonic::splitn] with `max` in place of [MAX_SPLIT], for when you really do want that
+    /// many shares.
+    #[cfg(feature = "std")]
+    pub fn splitn_with_max(self, n: usize, max: usize) -> Result<Vec<Self>, SeedXorError> {
+        if n > max {
+            return Err(SeedXorError::TooManyShares { max, got: n });
+        }
+        self.splitn_uncapped(n, RebuildMode::Recompute, &mut OsEntropy)
+    }
+
+    /// [Mnemonic::splitn_with_max], checking the shares recombine to the entropy of this
+    /// mnemonic and splitting again, up to `max_retries` more times, if they don't. Running out
+    /// of retries is [SeedXorError::Unverified].
+    ///
+    /// Only the entropy is compared, as shares always recombine with a freshly computed
+    /// checksum, so this also checks seeds parsed without a checksum check, like with
+    /// [Mnemonic::parse_normalized_without_checksum_check], which [Mnemonic::verify_roundtrip]
+    /// would always reject.
+    #[cfg(feature = "std")]
+    pub fn splitn_verified(
+        self,
+        n: usize,
+        max: usize,
+        max_retries: usize,
+    ) -> Result<Vec<Self>, SeedXorError> {
+        for _ in 0..=max_retries {
+            let shares = self.clone().splitn_with_max(n, max)?;
+            let actual = Self::xor_all(&shares)?;
+            if actual.word_count() == self.word_count() && actual.eq_up_to_padding(&self) {
+                return Ok(shares);
+            }
+        }
+        Err(SeedXorError::Unverified {
+            attempts: max_retries.saturating_add(1),
+        })
+    }
+
+    fn splitn_uncapped<R: EntropySource + ?Sized>(
+        self,
+        n: usize,
+        mode: RebuildMode,
+        rng: &mut R,
+    ) -> Result<Vec<Self>, SeedXorError> {
+        if n == 0 {
+            return Err(SeedXorError::EmptyInput);
+        }
         let mut ret: Vec<Self> = Vec::with_capacity(n);
         if n == 1 {
             ret.push(self);
         } else {
-            ret.extend_from_slice(&self.split()?);
+            ret.extend_from_slice(&self.split_with_mode_rng(mode, rng)?);
             for _ in 0..n - 2 {
-                let split = ret.pop().expect("cannot be empty").split()?;
+                let split = ret
+                    .pop()
+                    .expect("cannot be empty")
+                    .split_with_mode_rng(mode, rng)?;
                 ret.extend_from_slice(&split);
             }
         }
         Ok(ret)
     }
 
-    pub fn generate_in(language: Language, word_count: usize) -> Result<Self, Error> {
-        //let inner = bip39::Mnemonic::generate_in(language, word_count)?;
-        let mut inner = vec![0u8; (word_count / 3) * 4];
-        getrandom::getrandom(&mut inner)
-            .map_err(|e| Error::BadEntropyBitCount(e.code().get() as usize))?;
-        bip39::Mnemonic::from_entropy_in(language, &inner).map(|m| m.into())
+    /// Split into one share per entry of `word_counts`, each with that many words, which must
+    /// be a valid bip39 word count no shorter than this mnemonic, or [Error::BadWordCount]
+    /// with the offending count is returned.
+    ///
+    /// XOR takes on the surplus of the longer seed, so when any share is longer than this
+    /// mnemonic the shares recombine to this mnemonic's entropy zero padded to the longest
+    /// share, see [Mnemonic::eq_up_to_padding], and the first share of that length is the one
+    /// that makes it so.
+    #[cfg(feature = "std")]
+    pub fn splitn_mixed(&self, word_counts: &[usize]) -> Result<Vec<Self>, SeedXorError> {
+        let (mut seed, seed_len) = self.to_entropy_array();
+        for count in word_counts {
+            match entropy_len_from_word_count(*count) {
+                Some(len) if len >= seed_len => {}
+                _ => return Err(Error::BadWordCount(*count).into()),
+            }
+        }
+        let longest = word_counts
+            .iter()
+            .copied()
+            .max()
+            .ok_or(SeedXorError::EmptyInput)?;
+        let residual = word_counts
+            .iter()
+            .position(|c| *c == longest)
+            .expect("longest is one of them");
+        let mut entropy = vec![0u8; entropy_len_from_word_count(longest).expect("checked above")];
+        entropy[0..seed_len].copy_from_slice(&seed[0..seed_len]);
+
+        let mut ret = Vec::with_capacity(word_counts.len());
+        for (i, count) in word_counts.iter().enumerate() {
+            if i == residual {
+                // placeholder until every other share is known
+                ret.push(self.clone());
+                continue;
+            }
+            let random = Self::generate_in(self.language(), *count)?;
+            let (mut bytes, len) = random.to_entropy_array();
+            entropy
+                .iter_mut()
+                .zip(&bytes[0..len])
+                .for_each(|(e, b)| *e ^= b);
+            wipe(&mut bytes);
+            ret.push(random);
+        }
+        let residual_share = bip39::Mnemonic::from_entropy_in(self.language(), &entropy);
+        wipe(&mut seed);
+        wipe(&mut entropy);
+        ret[residual] = residual_share?.into();
+        Ok(ret)
+    }
+
+    /// Like [Mnemonic::splitn] but lazily yields the `n - 1` random shares one at a time,
+    /// followed by the residual share, so they never all have to be held in memory.
+    #[cfg(feature = "std")]
+    pub fn split_stream(&self, n: usize) -> impl Iterator<Item = Result<Self, SeedXorError>> {
+        let language = self.language();
+        let word_count = self.word_count();
+        let mut residual = Some(self.clone());
+        let mut remaining = n;
+        core::iter::from_fn(move || match remaining {
+            0 => None,
+            1 => {
+                remaining = 0;
+                residual.take().map(Ok)
+            }
+            _ => match Self::generate_in(language, word_count) {
+                Ok(random) => {
+                    remaining -= 1;
+                    let residual = residual.as_mut().expect("cannot be empty");
+                    *residual = residual.xor(&random);
+                    Some(Ok(random))
+                }
+                Err(e) => {
+                    remaining = 0;
+                    Some(Err(e))
+                }
+            },
+        })
+    }
+
+    /// A random mnemonic of `word_count` words, anything but a valid bip39 word count is
+    /// [Error::BadWordCount].
+    #[cfg(feature = "std")]
+    pub fn generate_in(language: Language, word_count: usize) -> Result<Self, SeedXorError> {
+        Self::generate_in_with_source(language, word_count, OsEntropy)
+    }
+
+    /// A random English mnemonic of `word_count` words, see [Mnemonic::generate_in].
+    #[cfg(feature = "std")]
+    pub fn random(word_count: usize) -> Result<Self, SeedXorError> {
+        Self::generate_in(Language::English, word_count)
+    }
+
+    /// A random 24 word English mnemonic, see [Mnemonic::random].
+    #[cfg(feature = "std")]
+    pub fn random_24() -> Result<Self, SeedXorError> {
+        Self::random(24)
+    }
+
+    /// Endless stream of fresh random mnemonics from [Mnemonic::generate_in].
+    #[cfg(feature = "std")]
+    pub fn random_iter(
+        language: Language,
+        word_count: usize,
+    ) -> impl Iterator<Item = Result<Self, SeedXorError>> {
+        core::iter::repeat_with(move || Self::generate_in(language, word_count))
+    }
+
+    /// Generate random mnemonics until one matches `predicate`, giving up with [None] after
+    /// `max_attempts`.
+    ///
+    /// Each word is one of 2048, so a specific first word (see [starts_with_word]) takes about
+    /// 2048 attempts on average, and a prefix shared by `k` words about `2048 / k`.
+    /// Every word fixed this way is roughly 11 bits of entropy an attacker who knows about it
+    /// no longer has to guess.
+    #[cfg(feature = "std")]
+    pub fn search_vanity(
+        language: Language,
+        word_count: usize,
+        predicate: impl Fn(&Mnemonic) -> bool,
+        max_attempts: usize,
+    ) -> Result<Option<Self>, SeedXorError> {
+        for mnemonic in Self::random_iter(language, word_count).take(max_attempts) {
+            let mnemonic = mnemonic?;
+            if predicate(&mnemonic) {
+                return Ok(Some(mnemonic));
+            }
+        }
+        Ok(None)
+    }
+
+    /// [Mnemonic::search_vanity] split across `threads` threads, returning as soon as any of
+    /// them finds a match, `max_attempts` is shared between all of them.
+    ///
+    /// Each thread draws its own entropy from the operating system so there is no shared RNG
+    /// state to seed.
+    #[cfg(feature = "std")]
+    pub fn search_vanity_parallel(
+        language: Language,
+        word_count: usize,
+        predicate: impl Fn(&Mnemonic) -> bool + Sync,
+        threads: usize,
+        max_attempts: usize,
+    ) -> Result<Option<Self>, SeedXorError> {
+        let done = AtomicBool::new(false);
+        let attempts = AtomicUsize::new(0);
+        let worker = || {
+            while !done.load(Ordering::Relaxed)
+                && attempts.fetch_add(1, Ordering::Relaxed) < max_attempts
+            {
+                let found = Self::generate_in(language, word_count)
+                    .map(|mnemonic| Some(mnemonic).filter(&predicate));
+                if !matches!(found, Ok(None)) {
+                    done.store(true, Ordering::Relaxed);
+                    return found;
+                }
+            }
+            Ok(None)
+        };
+        std::thread::scope(|s| {
+            let workers: Vec<_> = (0..threads.max(1)).map(|_| s.spawn(worker)).collect();
+            let mut ret = Ok(None);
+            // the first match, or failing that the first error
+            for worker in workers {
+                match worker.join().expect("vanity search thread panicked") {
+                    Ok(Some(m)) if !matches!(ret, Ok(Some(_))) => ret = Ok(Some(m)),
+                    Err(e) if matches!(ret, Ok(None)) => ret = Err(e),
+                    _ => {}
+                }
+            }
+            ret
+        })
     }
 
     /// Wrapper for the same method as in [bip39::Mnemonic].
-    pub fn from_entropy(entropy: &[u8]) -> Result<Self, Error> {
-        bip39::Mnemonic::from_entropy(entropy).map(|m| m.into())
+    pub fn from_entropy(entropy: &[u8]) -> Result<Self, SeedXorError> {
+        word_count_from_entropy_len(entropy.len())
+            .ok_or(Error::BadEntropyBitCount(entropy.len() * 8))?;
+        Ok(bip39::Mnemonic::from_entropy(entropy)?.into())
+    }
+
+    /// The entropy as lowercase hex, 32 to 64 digits, to paste into other tools.
+    pub fn to_entropy_hex(&self) -> String {
+        format!("{self:x}")
+    }
+
+    /// The [Mnemonic::entropy_bits] as `0` and `1`, most significant first, to show what XOR
+    /// does to each bit, see [fmt::Binary] for them grouped into bytes.
+    pub fn entropy_bit_string(&self) -> String {
+        let (entropy, entropy_len) = self.to_entropy_array();
+        entropy[0..entropy_len]
+            .iter()
+            .map(|b| format!("{b:08b}"))
+            .collect()
+    }
+
+    /// The entropy, only its [Mnemonic::entropy_bytes] rather than the padded array of
+    /// [Mnemonic::to_entropy_array], the inverse of [Mnemonic::from_entropy].
+    pub fn to_entropy_vec(&self) -> Vec<u8> {
+        let (mut entropy, entropy_len) = self.to_entropy_array();
+        let ret = entropy[0..entropy_len].to_vec();
+        wipe(&mut entropy);
+        ret
+    }
+
+    /// An English mnemonic from entropy as hex digits of either case, see
+    /// [Mnemonic::from_entropy].
+    ///
+    /// A digit that isn't hex is [SeedXorError::InvalidHex] of its index, and an odd number of
+    /// digits or one that isn't valid entropy [SeedXorError::InvalidHexLength].
+    pub fn from_entropy_hex(hex: &str) -> Result<Self, SeedXorError> {
+        let hex = hex.trim().as_bytes();
+        if let Some(i) = hex.iter().position(|c| !c.is_ascii_hexdigit()) {
+            return Err(SeedXorError::InvalidHex(i));
+        }
+        if !hex.len().is_multiple_of(2) || word_count_from_entropy_len(hex.len() / 2).is_none() {
+            return Err(SeedXorError::InvalidHexLength(hex.len()));
+        }
+        let digit = |c: u8| (c as char).to_digit(16).expect("checked hex digit") as u8;
+        let mut entropy: Vec<u8> = hex
+            .chunks(2)
+            .map(|pair| digit(pair[0]) << 4 | digit(pair[1]))
+            .collect();
+        let ret = Self::from_entropy(&entropy);
+        wipe(&mut entropy);
+        ret
+    }
+
+    /// The one language `s` is a valid mnemonic in, checksum included.
+    ///
+    /// [bip39::Mnemonic::language_of] only looks at as many words as it needs to tell the
+    /// wordlists apart, and assumes the mnemonic is valid, this checks every language.
+    /// A mnemonic valid in more than one language, which can happen when every word is in
+    /// both the English and French wordlists, is [Error::AmbiguousLanguages]. With no valid
+    /// language the error is why it didn't parse in the most likely one.
+    pub fn detect_language(s: &str) -> Result<Language, SeedXorError> {
+        let mut valid = Language::all()
+            .iter()
+            .filter(|l| bip39::Mnemonic::parse_in_normalized(**l, s).is_ok());
+        match (valid.next(), valid.next()) {
+            (Some(language), None) => Ok(*language),
+            // every word is in every valid language, so language_of can't pick one either
+            (Some(language), Some(_)) => {
+                bip39::Mnemonic::language_of(s)?;
+                Ok(*language)
+            }
+            (None, _) => {
+                let language = match bip39::Mnemonic::language_of(s) {
+                    Ok(language) => language,
+                    Err(Error::AmbiguousLanguages(a)) => a.iter().next().expect("at least 2"),
+                    Err(e) => return Err(e.into()),
+                };
+                bip39::Mnemonic::parse_in_normalized(language, s)?;
+                Ok(language)
+            }
+        }
     }
 
-    pub fn parse_normalized_without_checksum_check(s: &str) -> Result<Mnemonic, Error> {
+    /// Parse only canonical input: full words from the wordlist, in NFKD form, separated by
+    /// exactly one space, with no leading or trailing whitespace.
+    ///
+    /// Unlike [FromStr] no prefixes are expanded and no whitespace is collapsed, a token that
+    /// breaks any of these rules is reported as [Error::UnknownWord].
+    pub fn from_str_strict(s: &str) -> Result<Mnemonic, SeedXorError> {
+        let language = bip39::Mnemonic::language_of(s)?;
+        // wordlists are NFKD lowercase, so anything else will not be found
+        if let Some(i) = s.split(' ').position(|w| language.find_word(w).is_none()) {
+            return Err(Error::UnknownWord(i).into());
+        }
+        Ok(bip39::Mnemonic::parse_in_normalized(language, s)?.into())
+    }
+
+    pub fn parse_normalized_without_checksum_check(s: &str) -> Result<Mnemonic, SeedXorError> {
         let lang = bip39::Mnemonic::language_of(s).unwrap_or(Language::English);
         Self::parse_in_normalized_without_checksum_check(lang, s)
     }
@@ -138,139 +803,1010 @@ impl Mnemonic {
     pub fn parse_in_normalized_without_checksum_check(
         language: Language,
         s: &str,
-    ) -> Result<Mnemonic, Error> {
-        bip39::Mnemonic::parse_in_normalized_without_checksum_check(
+    ) -> Result<Mnemonic, SeedXorError> {
+        Ok(bip39::Mnemonic::parse_in_normalized_without_checksum_check(
             language,
             &expand_words_in(language, s)?,
-        )
-        .map(|m| m.into())
+        )?
+        .into())
     }
 
-    pub fn to_short_string(&self) -> String {
-        let mut ret = self.word_iter().fold(String::new(), |mut s, w| {
-            if w.len() == 3 {
-                s.push_str(w);
-                s.push_str("  ");
+    /// The mnemonic of the words at `indices` in the wordlist of `language`, checksum included.
+    ///
+    /// Same as [bip39::Mnemonic::parse_in_normalized] of those words but skips looking them
+    /// up, and an invalid checksum costs one SHA256 and no allocation, for checking many
+    /// orders of the same words as `--unscramble` does. An invalid word count is
+    /// [Error::BadWordCount] and an index past the wordlist [SeedXorError::IndexOutOfRange] of
+    /// its position and the 2048 words of the wordlist.
+    pub fn from_word_indices_in(
+        language: Language,
+        indices: &[u16],
+    ) -> Result<Mnemonic, SeedXorError> {
+        let entropy_len =
+            entropy_len_from_word_count(indices.len()).ok_or(Error::BadWordCount(indices.len()))?;
+        // entropy then checksum, at most 24 * 11 bits
+        let mut bits = [0u8; 33];
+        for (i, index) in indices.iter().enumerate() {
+            if *index >= 2048 {
+                return Err(SeedXorError::IndexOutOfRange {
+                    index: i,
+                    len: 2048,
+                });
+            }
+            for j in 0..11 {
+                if index >> (10 - j) & 1 == 1 {
+                    let bit = i * 11 + j;
+                    bits[bit / 8] |= 1 << (7 - bit % 8);
+                }
+            }
+        }
+        let checksum_bits = checksum_bits_for_words(indices.len());
+        let checksum = sha256::Hash::hash(&bits[0..entropy_len])[0] >> (8 - checksum_bits);
+        let ret = if bits[entropy_len] >> (8 - checksum_bits) == checksum {
+            Ok(bip39::Mnemonic::from_entropy_in(language, &bits[0..entropy_len])?.into())
+        } else {
+            Err(Error::InvalidChecksum.into())
+        };
+        wipe(&mut bits);
+        ret
+    }
+
+    /// Every word that completes `words` (all but the last word of a seed) into a valid mnemonic.
+    ///
+    /// The last word holds the final entropy bits as well as the checksum so there are
+    /// 128 valid last words for 12 word seeds, down to 8 for 24 word seeds.
+    pub fn valid_last_words(words: &[&str]) -> Result<Vec<&'static str>, SeedXorError> {
+        let lang = bip39::Mnemonic::language_of(words.join(" ")).unwrap_or(Language::English);
+        Self::valid_last_words_in(lang, words)
+    }
+
+    pub fn valid_last_words_in(
+        language: Language,
+        words: &[&str],
+    ) -> Result<Vec<&'static str>, SeedXorError> {
+        let word_count = words.len() + 1;
+        let entropy_len =
+            entropy_len_from_word_count(word_count).ok_or(Error::BadWordCount(word_count))?;
+        let mut bits = Vec::with_capacity(word_count * 11);
+        for (i, word) in words.iter().enumerate() {
+            let idx = language.find_word(word).ok_or(Error::UnknownWord(i))?;
+            bits.extend((0..11).rev().map(|j| (idx >> j) & 1 == 1));
+        }
+        let free_bits = 11 - checksum_bits_for_words(word_count);
+        (0..1u16 << free_bits)
+            .map(|last| {
+                let mut entropy = vec![0u8; entropy_len];
+                bits.iter()
+                    .copied()
+                    .chain((0..free_bits).rev().map(|j| (last >> j) & 1 == 1))
+                    .enumerate()
+                    .filter(|(_, bit)| *bit)
+                    .for_each(|(i, _)| entropy[i / 8] |= 1 << (7 - i % 8));
+                Ok(bip39::Mnemonic::from_entropy_in(language, &entropy)?
+                    .word_iter()
+                    .last()
+                    .expect("cannot be empty"))
+            })
+            .collect()
+    }
+
+    /// How many of the bits of the last word are checksum, see [checksum_bits_for_words].
+    pub fn checksum_bits_count(&self) -> usize {
+        checksum_bits_for_words(self.word_count())
+    }
+
+    /// Bits of entropy, the 11 bits of every word less the [Mnemonic::checksum_bits_count], 128
+    /// for 12 words up to 256 for 24.
+    pub fn entropy_bits(&self) -> usize {
+        self.word_count() * 11 - self.checksum_bits_count()
+    }
+
+    /// Bytes of entropy, see [Mnemonic::entropy_bits], the length [Mnemonic::from_entropy]
+    /// takes for this word count and [Mnemonic::to_entropy_array] fills.
+    pub fn entropy_bytes(&self) -> usize {
+        self.entropy_bits() / 8
+    }
+
+    /// The same entropy with a recomputed, valid bip39 checksum, for seeds parsed with
+    /// [Mnemonic::parse_normalized_without_checksum_check].
+    /// Splitting the fixed seed recombines to exactly the fixed seed.
+    pub fn checksum_fix(&self) -> Mnemonic {
+        let (entropy, entropy_len) = self.to_entropy_array();
+        // We unwrap here because the entropy came from a mnemonic with a valid word count.
+        bip39::Mnemonic::from_entropy_in(self.language(), &entropy[0..entropy_len])
+            .unwrap()
+            .into()
+    }
+
+    /// This mnemonic with the word at `index`, counted from 0, replaced by `new_word` of the
+    /// mnemonic's language and the checksum recomputed, for fixing one mistranscribed word.
+    ///
+    /// Only the last word's entropy bits come from `new_word` when `index` is the last word,
+    /// its checksum bits are recomputed like every other. An `index` past the last word is
+    /// [SeedXorError::IndexOutOfRange] and a `new_word` not in the wordlist
+    /// [Error::UnknownWord] of the index.
+    pub fn with_word_replaced(&self, index: usize, new_word: &str) -> Result<Self, SeedXorError> {
+        if index >= self.word_count() {
+            return Err(SeedXorError::IndexOutOfRange {
+                index,
+                len: self.word_count(),
+            });
+        }
+        let new_index = self
+            .language()
+            .find_word(new_word)
+            .ok_or(Error::UnknownWord(index))?;
+        let (mut entropy, entropy_len) = self.to_entropy_array();
+        // checksum bits past the entropy are dropped, from_entropy puts the new ones there
+        for j in 0..11 {
+            let bit = index * 11 + j;
+            if bit >= entropy_len * 8 {
+                break;
+            }
+            let mask = 1 << (7 - bit % 8);
+            if new_index >> (10 - j) & 1 == 1 {
+                entropy[bit / 8] |= mask;
             } else {
-                w.chars().take(4).for_each(|c| s.push(c));
-                s.push(' ');
+                entropy[bit / 8] &= !mask;
             }
+        }
+        let ret = bip39::Mnemonic::from_entropy_in(self.language(), &entropy[0..entropy_len]);
+        wipe(&mut entropy);
+        Ok(ret?.into())
+    }
+
+    /// The words truncated to [short_prefix_len] characters for the mnemonic's language,
+    /// which [expand_words_in] turns back into full words.
+    pub fn to_short_string(&self) -> String {
+        let len = short_prefix_len(self.language());
+        let mut ret = self.word_iter().fold(String::new(), |mut s, w| {
+            let mut chars = 0;
+            w.chars().take(len).for_each(|c| {
+                s.push(c);
+                chars += 1;
+            });
+            // pad words shorter than the prefix so columns line up
+            (chars..=len).for_each(|_| s.push(' '));
             s
         });
-        while ret.chars().last() == Some(' ') {
+        while ret.ends_with(' ') {
             ret.pop();
         }
         ret
     }
 
+    /// The words with all but the first `visible_start` and the last `visible_end` replaced by
+    /// `••••`, to check the ends of a backup on screen without showing the whole seed.
+    pub fn to_masked_string(&self, visible_start: usize, visible_end: usize) -> String {
+        let word_count = self.word_count();
+        let words: Vec<&str> = self
+            .word_iter()
+            .enumerate()
+            .map(|(i, word)| {
+                if i < visible_start || i + visible_end >= word_count {
+                    word
+                } else {
+                    "••••"
+                }
+            })
+            .collect();
+        words.join(" ")
+    }
+
+    /// [Mnemonic::to_short_string] but verify the short form expands back to this mnemonic.
+    ///
+    /// [expand_words] has to guess the language of the short form, which can go wrong for
+    /// languages other than English since a 4 letter prefix is rarely a full word.
+    /// If it does, [SeedXorError::AmbiguousShortForm] with the position of the first word that
+    /// doesn't expand back to itself is returned.
+    pub fn to_short_string_checked(&self) -> Result<String, SeedXorError> {
+        let short = self.to_short_string();
+        let expanded = match expand_words(&short) {
+            Err(SeedXorError::Bip39(Error::UnknownWord(i))) => {
+                return Err(SeedXorError::AmbiguousShortForm(i))
+            }
+            expanded => expanded?,
+        };
+        match self
+            .word_iter()
+            .zip(expanded.split_whitespace())
+            .position(|(a, b)| a != b)
+        {
+            None => Ok(short),
+            Some(i) => Err(SeedXorError::AmbiguousShortForm(i)),
+        }
+    }
+
+    /// Each word cut to the shortest prefix only it starts with in the wordlist of the
+    /// mnemonic's language, or kept whole if it starts another word, which [expand_words_in]
+    /// turns back into full words.
+    ///
+    /// Unlike [Mnemonic::to_short_string] the prefixes differ in length, so they don't line up,
+    /// but are often shorter.
+    pub fn to_unique_prefix_string(&self) -> String {
+        let language = self.language();
+        let words: Vec<&str> = self
+            .word_iter()
+            .map(|word| unique_prefix(language, word))
+            .collect();
+        words.join(" ")
+    }
+
+    /// The words numbered from 1 in rows of `columns`, like `1. romance  2. wink`, the numbers
+    /// and words padded so the columns line up, to write a backup down in a grid. A `columns`
+    /// of 0 puts every word in one row.
+    pub fn to_grid_string(&self, columns: usize) -> String {
+        grid(self.word_iter(), columns)
+    }
+
+    /// [Mnemonic::to_grid_string] of the words truncated like [Mnemonic::to_short_string].
+    pub fn to_short_grid_string(&self, columns: usize) -> String {
+        let len = short_prefix_len(self.language());
+        grid(
+            self.word_iter().map(|w| match w.char_indices().nth(len) {
+                Some((end, _)) => &w[..end],
+                None => w,
+            }),
+            columns,
+        )
+    }
+
     pub fn to_display_string(&self, short: bool) -> String {
         if short {
             self.to_short_string()
         } else {
-            self.to_string()
+            self.reveal()
         }
     }
-}
 
-pub fn expand_words(seed: &str) -> Result<String, Error> {
-    let lang = bip39::Mnemonic::language_of(seed).unwrap_or(Language::English);
-    expand_words_in(lang, seed)
-}
+    /// Overwrite the word indices, the only secret [bip39::Mnemonic] holds, with those of
+    /// all zero 12 word entropy, which is done on drop too.
+    ///
+    /// Copies already made by [Clone], [Mnemonic::reveal] or the like are not touched.
+    #[cfg(feature = "zeroize")]
+    pub fn zeroize(&mut self) {
+        // We unwrap here because 128 bits is a valid entropy length.
+        let blank = bip39::Mnemonic::from_entropy_in(self.language(), &[0; 16]).unwrap();
+        // SAFETY: self.inner is a valid, aligned, exclusive reference, and bip39::Mnemonic
+        // owns no heap memory that skipping its drop would leak.
+        unsafe { core::ptr::write_volatile(&mut self.inner, blank) };
+        core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+    }
 
-pub fn expand_words_in(language: Language, seed: &str) -> Result<String, Error> {
-    let mut ret = String::new();
-    for (i, prefix) in seed.to_lowercase().split_whitespace().enumerate() {
-        let words = language.words_by_prefix(prefix);
-        let word = if words.len() == 1 {
-            words[0]
-        } else if words.contains(&prefix) {
-            prefix
-        } else {
-            // println!("prefix: '{prefix}', words: {words:?}");
-            // not unique or correct prefix
-            return Err(Error::UnknownWord(i));
-        };
-        ret.push_str(word);
-        ret.push(' ');
+    /// The 64 byte bip39 seed with `normalized_passphrase`, which must already be NFKD
+    /// normalized, see [Mnemonic::to_seed].
+    pub fn to_seed_normalized(&self, normalized_passphrase: &str) -> [u8; 64] {
+        self.inner.to_seed_normalized(normalized_passphrase)
     }
-    ret.pop();
-    Ok(ret)
-}
 
-impl SeedXor for Mnemonic {
-    /// XOR two [Mnemonic]s without consuming them.
-    /// If consumption is not of relevance the XOR operator `^` and XOR assigner `^=` can be used as well.
-    fn xor(&self, rhs: &Self) -> Self {
-        self.inner.xor(&rhs.inner).into()
+    /// The 64 byte bip39 seed with `passphrase`.
+    ///
+    /// This crate has no unicode normalization tables, so only ASCII passphrases, which NFKD
+    /// leaves untouched, are accepted. Anything else would risk silently deriving a different
+    /// wallet, so is [SeedXorError::NonAsciiPassphrase] with the position of the first one,
+    /// normalize it yourself and use [Mnemonic::to_seed_normalized].
+    pub fn to_seed(&self, passphrase: &str) -> Result<[u8; 64], SeedXorError> {
+        if let Some(i) = passphrase.chars().position(|c| !c.is_ascii()) {
+            return Err(SeedXorError::NonAsciiPassphrase(i));
+        }
+        Ok(self.to_seed_normalized(passphrase))
     }
-}
 
-impl Deref for Mnemonic {
-    type Target = bip39::Mnemonic;
+    /// The words separated by spaces, which is also what `Display` shows unless the
+    /// `safe-default-display` feature is enabled.
+    pub fn reveal(&self) -> String {
+        let mut ret = String::new();
+        for word in self.word_iter() {
+            ret.push_str(word);
+            ret.push(' ');
+        }
+        ret.pop();
+        ret
+    }
 
-    fn deref(&self) -> &Self::Target {
-        &self.inner
+    /// The 11 bit word indices packed MSB first into bytes, that is the entropy followed by the
+    /// checksum bits, 132 bits for 12 words up to 264 bits for 24 words.
+    ///
+    /// Only 24 words fill the last byte, otherwise its low bits are zero padding, 4 bits for
+    /// 12 words, 1 for 15, 6 for 18 and 3 for 21.
+    pub fn to_entropy_with_checksum_bytes(&self) -> Vec<u8> {
+        let language = self.language();
+        let mut ret = vec![0u8; (self.word_count() * 11).div_ceil(8)];
+        for (w, word) in self.word_iter().enumerate() {
+            let idx = language.find_word(word).expect("valid word");
+            for bit in 0..11 {
+                if (idx >> (10 - bit)) & 1 == 1 {
+                    let i = w * 11 + bit;
+                    ret[i / 8] |= 0x80 >> (i % 8);
+                }
+            }
+        }
+        ret
     }
-}
 
-impl DerefMut for Mnemonic {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.inner
+    /// Niche comparison treating a shorter seed as equal to a longer one whose entropy starts
+    /// with the shorter seed's entropy and is zero for the rest, ie a 12 word seed and the same
+    /// seed zero-padded to 24 words.
+    ///
+    /// Seeds of equal length compare as usual, ignoring language.
+    ///
+    /// The entropy is compared in constant time like [Mnemonic::ct_eq], so checking a
+    /// recombined seed against a target doesn't leak how much of it matches.
+    pub fn eq_up_to_padding(&self, other: &Self) -> bool {
+        let (mut a, a_len) = self.to_entropy_array();
+        let (mut b, b_len) = other.to_entropy_array();
+        // checksum bits past the entropy are cleared, so both are zero padded to 32 bytes
+        a[a_len..].fill(0);
+        b[b_len..].fill(0);
+        let ret = ct_eq_bytes(&a, &b);
+        wipe(&mut a);
+        wipe(&mut b);
+        ret
     }
-}
 
-impl From<bip39::Mnemonic> for Mnemonic {
-    fn from(inner: bip39::Mnemonic) -> Self {
-        Self { inner }
+    /// Same as `==` but the words, checksum bits included, are compared in constant time
+    /// instead of stopping at the first word that differs, for comparing secrets such as a
+    /// recombined seed against a target.
+    ///
+    /// Looking the words up in the wordlist still takes time that depends on each word of
+    /// each mnemonic alone, but not on how many of them the two have in common. Word counts
+    /// and languages are not secret and compared as usual.
+    ///
+    /// It is written without `subtle`, so it costs no dependency and is not behind a feature,
+    /// which also lets [Secret] and [Mnemonic::eq_up_to_padding] always compare with it.
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        let (mut a, a_len) = self.to_entropy_array();
+        let (mut b, b_len) = other.to_entropy_array();
+        let ret = ct_eq_bytes(&a, &b);
+        wipe(&mut a);
+        wipe(&mut b);
+        ret && a_len == b_len && self.language() == other.language()
+    }
+
+    /// How many words differ between this and `other`, by position and word index so the
+    /// same seed in two languages has none, to spot a mistyped word of a backup. Mnemonics
+    /// of different word counts are [SeedXorError::LengthMismatch].
+    pub fn word_distance(&self, other: &Self) -> Result<usize, SeedXorError> {
+        if self.word_count() != other.word_count() {
+            return Err(SeedXorError::LengthMismatch {
+                expected: self.word_count(),
+                got: other.word_count(),
+            });
+        }
+        let (a, b) = (self.language(), other.language());
+        Ok(self
+            .word_iter()
+            .zip(other.word_iter())
+            .filter(|(x, y)| a.find_word(x) != b.find_word(y))
+            .count())
+    }
+
+    /// How many bits of entropy differ between this and `other`, the ones set in their XOR,
+    /// so a shorter mnemonic counts as zero padded like [Mnemonic::eq_up_to_padding].
+    pub fn bit_distance(&self, other: &Self) -> usize {
+        let (mut a, a_len) = self.to_entropy_array();
+        let (mut b, b_len) = other.to_entropy_array();
+        a[a_len..].fill(0);
+        b[b_len..].fill(0);
+        let ret = a
+            .iter()
+            .zip(&b)
+            .map(|(a, b)| (a ^ b).count_ones() as usize)
+            .sum();
+        wipe(&mut a);
+        wipe(&mut b);
+        ret
+    }
+
+    /// Check that `shares` recombine to exactly this mnemonic.
+    pub fn verify_roundtrip(&self, shares: &[Mnemonic]) -> Result<(), RoundtripError> {
+        let actual = Mnemonic::xor_all(shares).ok();
+        if actual.as_ref() == Some(self) {
+            Ok(())
+        } else {
+            Err(RoundtripError {
+                expected: self.clone(),
+                actual,
+            })
+        }
     }
 }
 
-impl FromStr for Mnemonic {
-    type Err = bip39::Error;
+/// A share along with a name for who or where it is given to, see [Mnemonic::split_with_labels].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LabeledShare {
+    pub label: String,
+    pub share: Mnemonic,
+}
 
-    fn from_str(mnemonic: &str) -> Result<Self, <Self as FromStr>::Err> {
-        bip39::Mnemonic::from_str(&expand_words(mnemonic)?).map(|m| m.into())
+impl fmt::Display for LabeledShare {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.label, self.share)
     }
 }
 
-impl fmt::Display for Mnemonic {
+/// Returned by [Mnemonic::verify_roundtrip] when the shares do not recombine to the expected seed.
+///
+/// `Display` and `Debug` only show a short fingerprint of each mnemonic so the error can be
+/// printed or logged without leaking the seeds.
+#[derive(Clone, PartialEq, Eq)]
+pub struct RoundtripError {
+    /// The mnemonic the shares should have recombined to.
+    pub expected: Mnemonic,
+    /// What the shares actually recombined to, [None] if there were no shares.
+    pub actual: Option<Mnemonic>,
+}
+
+/// First 4 bytes of the sha256 of the entropy, safe to display in place of the mnemonic.
+fn redacted(mnemonic: &Mnemonic) -> String {
+    let (entropy, entropy_len) = mnemonic.to_entropy_array();
+    let hash = sha256::Hash::hash(&entropy[0..entropy_len]);
+    hash[0..4].iter().map(|b| format!("{b:02x}")).collect()
+}
+
+impl fmt::Display for RoundtripError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for (i, word) in self.inner.word_iter().enumerate() {
-            if i > 0 {
-                f.write_str(" ")?;
-            }
-            f.write_str(word)?;
+        match &self.actual {
+            Some(actual) => write!(
+                f,
+                "shares recombine to {} but expected {}",
+                redacted(actual),
+                redacted(&self.expected)
+            ),
+            None => write!(
+                f,
+                "no shares to recombine, expected {}",
+                redacted(&self.expected)
+            ),
         }
-        Ok(())
     }
 }
 
-impl fmt::Debug for Mnemonic {
+impl fmt::Debug for RoundtripError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        <Mnemonic as Display>::fmt(self, f)
+        f.debug_struct("RoundtripError")
+            .field("expected", &redacted(&self.expected))
+            .field("actual", &self.actual.as_ref().map(redacted))
+            .finish()
     }
 }
 
-impl BitXor for Mnemonic {
-    type Output = Self;
+impl core::error::Error for RoundtripError {}
 
-    fn bitxor(self, rhs: Self) -> Self::Output {
-        self.xor(&rhs)
-    }
+/// XOR the entropy of `shares` regardless of the language each is written in, and build the
+/// result in `output_language`.
+///
+/// [SeedXor::xor] already works on entropy and always produces English, this is the explicit
+/// opt-in for combining shares that were written down in different languages.
+pub fn combine_entropy_crosslang(
+    shares: &[Mnemonic],
+    output_language: Language,
+) -> Result<Mnemonic, SeedXorError> {
+    let combined = Mnemonic::xor_all(shares)?;
+    let (entropy, entropy_len) = combined.to_entropy_array();
+    Ok(bip39::Mnemonic::from_entropy_in(output_language, &entropy[0..entropy_len])?.into())
 }
 
-impl BitXorAssign for Mnemonic {
-    fn bitxor_assign(&mut self, rhs: Self) {
-        *self = self.xor(&rhs)
+/// XOR the entropy of every share in `shares` ignoring their checksums, taking on the surplus
+/// of longer shares, and return the raw bytes with no mnemonic or checksum rebuilt.
+///
+/// This is the way back for arbitrary secrets, not necessarily bip39 seeds, that were split
+/// into shares. No shares is [SeedXorError::EmptyInput].
+pub fn combine_raw_any(shares: &[&str]) -> Result<Vec<u8>, SeedXorError> {
+    let mut ret: Option<Vec<u8>> = None;
+    for share in shares {
+        let (entropy, entropy_len) =
+            Mnemonic::parse_normalized_without_checksum_check(share)?.to_entropy_array();
+        let entropy = &entropy[0..entropy_len];
+        let ret = ret.get_or_insert_with(Vec::new);
+        ret.iter_mut().zip(entropy).for_each(|(a, b)| *a ^= b);
+        if ret.len() < entropy.len() {
+            ret.extend_from_slice(&entropy[ret.len()..]);
+        }
     }
+    ret.ok_or(SeedXorError::EmptyInput)
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::*;
-    use std::str::FromStr;
+/// Predicate for [Mnemonic::search_vanity] matching mnemonics whose first word starts with `prefix`.
+/// Note a full word like `add` also matches longer words such as `addict`.
+pub fn starts_with_word(prefix: &str) -> impl Fn(&Mnemonic) -> bool + '_ {
+    move |m| m.word_iter().next().is_some_and(|w| w.starts_with(prefix))
+}
 
-    #[test]
+/// Combine XOR `shares` and report the indices of any that were not needed, like
+/// [combine_threshold_report] does for threshold shares.
+///
+/// Plain XOR shares are n-of-n so every share is always needed and the list is empty.
+pub fn combine_report(shares: &[Mnemonic]) -> Result<(Mnemonic, Vec<usize>), SeedXorError> {
+    let seed = Mnemonic::xor_all(shares)?;
+    Ok((seed, Vec::new()))
+}
+
+/// Combine `shares` and derive the 64 byte bip39 seed with `passphrase` in one step, for
+/// schemes that keep part of the secret as the passphrase so the combined mnemonic alone is
+/// not enough.
+///
+/// The combined mnemonic is dropped before returning. Like [Mnemonic::to_seed], a non-ASCII
+/// passphrase is [SeedXorError::NonAsciiPassphrase].
+pub fn reconstruct_with_passphrase(
+    shares: &[Mnemonic],
+    passphrase: &str,
+) -> Result<[u8; 64], SeedXorError> {
+    let combined = Mnemonic::xor_all(shares)?;
+    combined.to_seed(passphrase)
+}
+
+/// Shuffle `shares` in place with a Fisher-Yates shuffle, drawing randomness from `rng`.
+///
+/// XOR doesn't care about order, shuffling just removes any hint of which share was the
+/// residual one from [Mnemonic::splitn].
+pub fn shuffle_shares(shares: &mut [Mnemonic], mut rng: impl FnMut() -> u64) {
+    for i in (1..shares.len()).rev() {
+        let j = (rng() % (i as u64 + 1)) as usize;
+        shares.swap(i, j);
+    }
+}
+
+/// Checksum bits of a mnemonic of `words` words, 1 per 3 words so 4 for 12 words up to 8 for
+/// 24, all held by the last word along with `11 -` that many entropy bits.
+pub fn checksum_bits_for_words(words: usize) -> usize {
+    words / 3
+}
+
+/// Words of a mnemonic of `len` bytes of entropy, [None] unless `len` is a valid bip39
+/// length, 16 to 32 bytes in steps of 4.
+pub fn word_count_from_entropy_len(len: usize) -> Option<usize> {
+    ((16..=32).contains(&len) && len.is_multiple_of(4)).then_some(len / 4 * 3)
+}
+
+/// Bytes of entropy of a mnemonic of `words` words, [None] unless `words` is a valid bip39
+/// word count, 12 to 24 in steps of 3.
+pub fn entropy_len_from_word_count(words: usize) -> Option<usize> {
+    ((12..=24).contains(&words) && words.is_multiple_of(3)).then(|| words / 3 * 4)
+}
+
+/// `words` numbered from 1 in rows of `columns`, see [Mnemonic::to_grid_string].
+fn grid<'a>(words: impl Iterator<Item = &'a str>, columns: usize) -> String {
+    let words: Vec<&str> = words.collect();
+    let columns = if columns == 0 { words.len() } else { columns };
+    let number_width = format!("{}", words.len()).len();
+    let word_width = words.iter().map(|w| w.chars().count()).max().unwrap_or(0);
+    let rows: Vec<String> = words
+        .chunks(columns.max(1))
+        .enumerate()
+        .map(|(row, words)| {
+            let cells: Vec<String> = words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| {
+                    let number = row * columns + i + 1;
+                    format!("{number:>number_width$}. {word:<word_width$}")
+                })
+                .collect();
+            cells.join("  ").trim_end().to_owned()
+        })
+        .collect();
+    rows.join("\n")
+}
+
+/// Shortest prefix of `word`, a word of `language`, that no other word starts with, or `word`
+/// itself if it starts other words, like `act` does `action`.
+fn unique_prefix(language: Language, word: &str) -> &str {
+    word.char_indices()
+        .skip(1)
+        .map(|(end, _)| &word[..end])
+        .find(|prefix| unique_word_by_prefix(language, prefix).is_some())
+        .unwrap_or(word)
+}
+
+/// The one word of `language` starting with `prefix`, [None] if there are none or several.
+///
+/// Every word is checked as [Language::words_by_prefix] only finds the first run of words
+/// with the prefix, and the accents of wordlists like French break their byte order.
+fn unique_word_by_prefix(language: Language, prefix: &str) -> Option<&'static str> {
+    let mut words = language
+        .word_list()
+        .iter()
+        .filter(|w| w.starts_with(prefix));
+    match (words.next(), words.next()) {
+        (Some(word), None) => Some(word),
+        _ => None,
+    }
+}
+
+/// A value for every language, computed on first use and cached with the `std` feature, or
+/// computed on every use without it as there is no `OnceLock`.
+struct PerLanguage<T> {
+    #[cfg(feature = "std")]
+    cache: OnceLock<Vec<(Language, T)>>,
+    #[cfg(not(feature = "std"))]
+    cache: core::marker::PhantomData<T>,
+}
+
+impl<T: Copy> PerLanguage<T> {
+    const fn new() -> Self {
+        PerLanguage {
+            #[cfg(feature = "std")]
+            cache: OnceLock::new(),
+            #[cfg(not(feature = "std"))]
+            cache: core::marker::PhantomData,
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn get(&self, language: Language, f: fn(Language) -> T) -> T {
+        self.cache
+            .get_or_init(|| Language::all().iter().map(|l| (*l, f(*l))).collect())
+            .iter()
+            .find(|(l, _)| *l == language)
+            .map(|(_, value)| *value)
+            .expect("every language is listed")
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn get(&self, language: Language, f: fn(Language) -> T) -> T {
+        f(language)
+    }
+}
+
+/// Shortest prefix, in characters, that tells every word of `language` apart.
+///
+/// This is 4 for English, but more for wordlists like French or Japanese, and 1 for Chinese.
+pub fn short_prefix_len(language: Language) -> usize {
+    static LENS: PerLanguage<usize> = PerLanguage::new();
+    LENS.get(language, |language| {
+        let words = language.word_list();
+        (1..)
+            .find(|len| {
+                let prefixes: BTreeSet<String> = words
+                    .iter()
+                    .map(|w| w.chars().take(*len).collect())
+                    .collect();
+                prefixes.len() == words.len()
+            })
+            .expect("words are unique")
+    })
+}
+
+/// Whether any word of `language` is not all lowercase, in which case input can't be
+/// lowercased without changing its meaning. No bip39 wordlist is today.
+fn case_matters(language: Language) -> bool {
+    static CASED: PerLanguage<bool> = PerLanguage::new();
+    CASED.get(language, |language| {
+        language.word_list().iter().any(|w| w.to_lowercase() != *w)
+    })
+}
+
+/// Normalize a single typed word, or word prefix, for lookup in the `language` wordlist.
+///
+/// This lowercases, unless case matters for any word of the wordlist. Wordlists are in NFKD form, which
+/// is not applied here as that needs unicode tables, so input must already be NFKD to match.
+pub fn normalize_token_in(language: Language, token: &str) -> String {
+    if case_matters(language) {
+        token.to_owned()
+    } else {
+        token.to_lowercase()
+    }
+}
+
+pub fn expand_words(seed: &str) -> Result<String, SeedXorError> {
+    let lang = bip39::Mnemonic::language_of(seed).unwrap_or(Language::English);
+    expand_words_in(lang, seed)
+}
+
+pub fn expand_words_in(language: Language, seed: &str) -> Result<String, SeedXorError> {
+    let mut ret = String::new();
+    for (i, prefix) in seed.split_whitespace().enumerate() {
+        let prefix = normalize_token_in(language, prefix);
+        let prefix = prefix.as_str();
+        let word = match language.find_word(prefix) {
+            // a whole word, even if longer words like add's addict start with it
+            Some(index) => language.word_list()[index as usize],
+            None => match unique_word_by_prefix(language, prefix) {
+                Some(word) => word,
+                // not unique or correct prefix
+                None => return Err(Error::UnknownWord(i).into()),
+            },
+        };
+        ret.push_str(word);
+        ret.push(' ');
+    }
+    ret.pop();
+    Ok(ret)
+}
+
+/// Every whole word of a language and every prefix only one word starts with, sorted for
+/// binary search, so unlike [expand_words_in] expanding a word doesn't scan the wordlist.
+///
+/// Build it once with [PrefixMap::new] for [expand_words_with_map] when expanding many seeds,
+/// like every order of the parts to unscramble.
+#[derive(Clone, Debug)]
+pub struct PrefixMap {
+    language: Language,
+    /// Prefix, or whole word, and the word it expands to, every prefix a slice of its word.
+    prefixes: Vec<(&'static str, &'static str)>,
+}
+
+impl PrefixMap {
+    pub fn new(language: Language) -> Self {
+        let mut words = language.word_list().to_vec();
+        words.sort_unstable();
+        let shared =
+            |a: &str, b: &str| a.chars().zip(b.chars()).take_while(|(a, b)| a == b).count();
+        let mut prefixes = Vec::new();
+        for (i, word) in words.iter().enumerate() {
+            // in sorted order the words sharing the longest prefix with word are next to it
+            let longest = [i.checked_sub(1), Some(i + 1)]
+                .iter()
+                .flatten()
+                .filter_map(|j| words.get(*j))
+                .map(|other| shared(word, other))
+                .max()
+                .unwrap_or(0);
+            let ends = word.char_indices().map(|(i, c)| i + c.len_utf8());
+            prefixes.extend(ends.skip(longest).map(|end| (&word[..end], *word)));
+            if longest == word.chars().count() {
+                // starts longer words, like act does action
+                prefixes.push((word, word));
+            }
+        }
+        prefixes.sort_unstable();
+        PrefixMap { language, prefixes }
+    }
+
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// The word `prefix`, already normalized, is or uniquely starts, [None] if there are none
+    /// or several.
+    pub fn get(&self, prefix: &str) -> Option<&'static str> {
+        self.prefixes
+            .binary_search_by_key(&prefix, |(prefix, _)| prefix)
+            .ok()
+            .map(|i| self.prefixes[i].1)
+    }
+}
+
+/// [expand_words_in] the language of `map`, looking words up in it instead of scanning the
+/// wordlist for each one.
+pub fn expand_words_with_map(map: &PrefixMap, seed: &str) -> Result<String, SeedXorError> {
+    let mut ret = String::new();
+    for (i, prefix) in seed.split_whitespace().enumerate() {
+        let word = map
+            .get(&normalize_token_in(map.language, prefix))
+            .ok_or(Error::UnknownWord(i))?;
+        ret.push_str(word);
+        ret.push(' ');
+    }
+    ret.pop();
+    Ok(ret)
+}
+
+impl SeedXor for Mnemonic {
+    /// XOR two [Mnemonic]s without consuming them.
+    /// If consumption is not of relevance the XOR operator `^` and XOR assigner `^=` can be used as well.
+    fn xor(&self, rhs: &Self) -> Self {
+        self.inner.xor(&rhs.inner).into()
+    }
+}
+
+impl Deref for Mnemonic {
+    type Target = bip39::Mnemonic;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl DerefMut for Mnemonic {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl From<bip39::Mnemonic> for Mnemonic {
+    fn from(inner: bip39::Mnemonic) -> Self {
+        Self { inner }
+    }
+}
+
+impl From<[u8; 16]> for Mnemonic {
+    /// 16 bytes of entropy is always a valid 12 word [Mnemonic].
+    fn from(entropy: [u8; 16]) -> Self {
+        // We unwrap here because 128 bits is a valid entropy length.
+        Self::from_entropy(&entropy).unwrap()
+    }
+}
+
+impl From<[u8; 32]> for Mnemonic {
+    /// 32 bytes of entropy is always a valid 24 word [Mnemonic].
+    fn from(entropy: [u8; 32]) -> Self {
+        // We unwrap here because 256 bits is a valid entropy length.
+        Self::from_entropy(&entropy).unwrap()
+    }
+}
+
+impl TryFrom<&[u8]> for Mnemonic {
+    type Error = SeedXorError;
+
+    /// An English mnemonic from entropy of any valid length, see [Mnemonic::from_entropy].
+    fn try_from(entropy: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_entropy(entropy)
+    }
+}
+
+impl FromStr for Mnemonic {
+    type Err = SeedXorError;
+
+    /// Parse words, or prefixes of words, see [expand_words].
+    /// Input made up only of digits is parsed as SeedQR, see [Mnemonic::from_seedqr_digits].
+    fn from_str(mnemonic: &str) -> Result<Self, <Self as FromStr>::Err> {
+        if seedqr::is_seedqr_digits(mnemonic.trim()) {
+            return Self::from_seedqr_digits(mnemonic.trim());
+        }
+        Ok(bip39::Mnemonic::from_str(&expand_words(mnemonic)?)?.into())
+    }
+}
+
+/// Compares after the same normalization [FromStr] applies, so word prefixes, any whitespace
+/// and SeedQR digits all compare equal to the mnemonic they parse as.
+impl PartialEq<str> for Mnemonic {
+    fn eq(&self, other: &str) -> bool {
+        if seedqr::is_seedqr_digits(other.trim()) {
+            return Self::from_seedqr_digits(other.trim()).is_ok_and(|m| m == *self);
+        }
+        expand_words_in(self.language(), other).is_ok_and(|words| words == self.reveal())
+    }
+}
+
+impl PartialEq<&str> for Mnemonic {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+/// The words, or with the `safe-default-display` feature only the word count and a short
+/// fingerprint, in which case use [Mnemonic::reveal] for the words.
+impl fmt::Display for Mnemonic {
+    #[cfg(feature = "safe-default-display")]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Mnemonic({} words, {})",
+            self.word_count(),
+            redacted(self)
+        )
+    }
+
+    #[cfg(not(feature = "safe-default-display"))]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, word) in self.inner.word_iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(word)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        <Mnemonic as Display>::fmt(self, f)
+    }
+}
+
+/// The entropy as lowercase hex, like [Mnemonic::to_entropy_hex], with `#` prefixed by `0x`.
+impl fmt::LowerHex for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (entropy, entropy_len) = self.to_entropy_array();
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        entropy[0..entropy_len]
+            .iter()
+            .try_for_each(|b| write!(f, "{b:02x}"))
+    }
+}
+
+/// The entropy as uppercase hex, with `#` prefixed by `0x`.
+impl fmt::UpperHex for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (entropy, entropy_len) = self.to_entropy_array();
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        entropy[0..entropy_len]
+            .iter()
+            .try_for_each(|b| write!(f, "{b:02X}"))
+    }
+}
+
+/// The entropy bits, a group of 8 for each byte separated by spaces, with `#` prefixed by
+/// `0b`, see [Mnemonic::entropy_bit_string].
+impl fmt::Binary for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (entropy, entropy_len) = self.to_entropy_array();
+        if f.alternate() {
+            f.write_str("0b")?;
+        }
+        for (i, b) in entropy[0..entropy_len].iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{b:08b}")?;
+        }
+        Ok(())
+    }
+}
+
+/// How many times the not zeroized warning was printed, only ever 0 or 1.
+#[cfg(all(
+    feature = "warn-unzeroized",
+    not(feature = "zeroize"),
+    debug_assertions
+))]
+static UNZEROIZED_WARNINGS: core::sync::atomic::AtomicUsize =
+    core::sync::atomic::AtomicUsize::new(0);
+
+/// Development aid: warn once, in debug builds only, that dropped mnemonics leave their
+/// secret material behind in memory.
+#[cfg(all(
+    feature = "warn-unzeroized",
+    not(feature = "zeroize"),
+    debug_assertions
+))]
+impl Drop for Mnemonic {
+    fn drop(&mut self) {
+        use core::sync::atomic::Ordering;
+        if UNZEROIZED_WARNINGS
+            .compare_exchange(0, 1, Ordering::Relaxed, Ordering::Relaxed)
+            .is_ok()
+        {
+            eprintln!("warning: seedxor::Mnemonic dropped without zeroizing its secret material");
+        }
+    }
+}
+
+/// Wipe the word indices on drop, see [Mnemonic::zeroize].
+#[cfg(feature = "zeroize")]
+impl Drop for Mnemonic {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Zeroize for Mnemonic {
+    fn zeroize(&mut self) {
+        Mnemonic::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl ZeroizeOnDrop for Mnemonic {}
+
+impl BitXor for Mnemonic {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.xor(&rhs)
+    }
+}
+
+impl BitXorAssign for Mnemonic {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = self.xor(&rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::str::FromStr;
+
+    #[test]
     fn seed_xor_works() {
         // Coldcard example: https://github.com/Coldcard/firmware/blob/master/docs/seed-xor.md
         let a_str = "romance wink lottery autumn shop bring dawn tongue range crater truth ability miss spice fitness easy legal release recall obey exchange recycle dragon room";
@@ -278,109 +1814,1371 @@ mod tests {
         let c_str = "vault nominee cradle silk own frown throw leg cactus recall talent worry gadget surface shy planet purpose coffee drip few seven term squeeze educate";
         let result_str = "silent toe meat possible chair blossom wait occur this worth option bag nurse find fish scene bench asthma bike wage world quit primary indoor";
 
-        let a = Mnemonic::from_str(a_str).unwrap();
-        let b = Mnemonic::from_str(b_str).unwrap();
-        let c = Mnemonic::from_str(c_str).unwrap();
-        let result = Mnemonic::from_str(result_str).unwrap();
+        let a = Mnemonic::from_str(a_str).unwrap();
+        let b = Mnemonic::from_str(b_str).unwrap();
+        let c = Mnemonic::from_str(c_str).unwrap();
+        let result = Mnemonic::from_str(result_str).unwrap();
+
+        assert_eq!(result, a.clone() ^ b.clone() ^ c.clone());
+        assert_eq!(result, b ^ c ^ a); // Commutative
+        assert_eq!(Err(SeedXorError::EmptyInput), Mnemonic::xor_all(&[]));
+    }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn zeroize_works() {
+        let mut buf = [0xffu8; 33];
+        wipe(&mut buf[1..]);
+        assert_eq!(0xff, buf[0]);
+        assert!(buf[1..].iter().all(|b| *b == 0));
+
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let mut seed = Mnemonic::from_str(seed).unwrap();
+        seed.zeroize();
+        assert_eq!(Mnemonic::from([0u8; 16]), seed);
+        let mut seed = Mnemonic::from([7u8; 32]);
+        seed.zeroize();
+        assert_eq!(Mnemonic::from([0u8; 16]), seed);
+
+        fn zeroize_on_drop<T: Zeroize + ZeroizeOnDrop>(value: &mut T) {
+            value.zeroize();
+        }
+        let mut seed = Mnemonic::from([7u8; 32]);
+        zeroize_on_drop(&mut seed);
+        assert_eq!(Mnemonic::from([0u8; 16]), seed);
+        let mut buf = [0xffu8; 16];
+        Zeroize::zeroize(&mut buf[..]);
+        assert!(buf.iter().all(|b| *b == 0));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn splitn_shares_are_valid_mnemonics() {
+        let seed = "romance wink lottery autumn shop bring dawn tongue range crater truth ability miss spice fitness easy legal release recall obey exchange recycle dragon room";
+        let seed = Mnemonic::from_str(seed).unwrap();
+        for n in [2, 3, 7] {
+            for share in seed.clone().splitn(n).unwrap() {
+                let words = share.reveal();
+                assert_eq!(24, share.word_count());
+                assert_eq!(share, Mnemonic::from_str_strict(&words).unwrap());
+                assert_eq!(
+                    share,
+                    Mnemonic::from(bip39::Mnemonic::parse_normalized(&words).unwrap())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn eq_str() {
+        // Coldcard example: https://github.com/Coldcard/firmware/blob/master/docs/seed-xor.md
+        let a_str = "romance wink lottery autumn shop bring dawn tongue range crater truth ability miss spice fitness easy legal release recall obey exchange recycle dragon room";
+        let a = Mnemonic::from_str(a_str).unwrap();
+        assert!(a == a_str);
+        assert!(a == *a_str);
+        assert_eq!(a, a_str);
+        assert!(a == "  ROMA wink lott autu shop brin dawn tong rang crat trut abil miss spic fitn easy lega rele reca obey exch recy drag room\n");
+        assert!(a != "romance wink lottery autumn shop bring dawn tongue range crater truth ability miss spice fitness easy legal release recall obey exchange recycle dragon");
+        assert!(a != "not even words");
+        assert!(a != "");
+
+        let seedqr = "192402220235174306311124037817700641198012901210";
+        assert!(Mnemonic::from_str(seedqr).unwrap() == seedqr);
+        assert!(a != seedqr);
+    }
+
+    #[test]
+    fn seed_xor_assignment_works() {
+        // Coldcard example: https://github.com/Coldcard/firmware/blob/master/docs/seed-xor.md
+        let a_str = "romance wink lottery autumn shop bring dawn tongue range crater truth ability miss spice fitness easy legal release recall obey exchange recycle dragon room";
+        let b_str = "lion misery divide hurry latin fluid camp advance illegal lab pyramid unaware eager fringe sick camera series noodle toy crowd jeans select depth lounge";
+        let c_str = "vault nominee cradle silk own frown throw leg cactus recall talent worry gadget surface shy planet purpose coffee drip few seven term squeeze educate";
+        let result_str = "silent toe meat possible chair blossom wait occur this worth option bag nurse find fish scene bench asthma bike wage world quit primary indoor";
+
+        let a = Mnemonic::from_str(a_str).unwrap();
+        let b = Mnemonic::from_str(b_str).unwrap();
+        let c = Mnemonic::from_str(c_str).unwrap();
+        let result = Mnemonic::from_str(result_str).unwrap();
+
+        let mut assigned = a.xor(&b); // XOR without consuming
+        assigned ^= c;
+
+        assert_eq!(result, assigned);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn xor_strict_lengths() {
+        let short = Mnemonic::from([0x42u8; 16]);
+        let long = Mnemonic::from([0x24u8; 32]);
+        assert_eq!(
+            Err(SeedXorError::LengthMismatch {
+                expected: 12,
+                got: 24
+            }),
+            short.xor_strict(&long)
+        );
+        assert_eq!(
+            Err(SeedXorError::LengthMismatch {
+                expected: 24,
+                got: 12
+            }),
+            long.xor_strict(&short)
+        );
+        assert_eq!(
+            Err(SeedXorError::LengthMismatch {
+                expected: 24,
+                got: 12
+            }),
+            Mnemonic::xor_all_strict(&[long.clone(), long.clone(), short.clone()])
+        );
+        assert_eq!(Ok(short.xor(&short)), short.xor_strict(&short));
+        let shares = long.clone().splitn(3).unwrap();
+        assert_eq!(Ok(long), Mnemonic::xor_all_strict(&shares));
+        assert_eq!(Err(SeedXorError::EmptyInput), Mnemonic::xor_all_strict(&[]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn xor_into_works() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let seed = Mnemonic::from_str(seed).unwrap();
+        let shares = seed.clone().splitn(4).unwrap();
+        let mut combined = shares[0].clone();
+        for share in &shares[1..] {
+            combined.xor_into(share);
+        }
+        assert_eq!(seed, combined);
+        assert_eq!(Mnemonic::xor_all_fast(&shares), Ok(combined));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn xor_iter_works() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let seed = Mnemonic::from_str(seed).unwrap();
+        let shares = seed.clone().splitn(3).unwrap();
+        assert_eq!(
+            Mnemonic::xor_all(&shares),
+            Mnemonic::xor_iter(shares.clone())
+        );
+        assert_eq!(Ok(seed), Mnemonic::xor_iter(shares.into_iter()));
+        assert_eq!(
+            Err(SeedXorError::EmptyInput),
+            Mnemonic::xor_iter(std::iter::empty())
+        );
+    }
+
+    #[test]
+    fn xor_all_fast_works() {
+        // Coldcard example: https://github.com/Coldcard/firmware/blob/master/docs/seed-xor.md
+        let a_str = "romance wink lottery autumn shop bring dawn tongue range crater truth ability miss spice fitness easy legal release recall obey exchange recycle dragon room";
+        let b_str = "lion misery divide hurry latin fluid camp advance illegal lab pyramid unaware eager fringe sick camera series noodle toy crowd jeans select depth lounge";
+        let c_str = "vault nominee cradle silk own frown throw leg cactus recall talent worry gadget surface shy planet purpose coffee drip few seven term squeeze educate";
+        let result_str = "silent toe meat possible chair blossom wait occur this worth option bag nurse find fish scene bench asthma bike wage world quit primary indoor";
+
+        let shares = [a_str, b_str, c_str].map(|s| Mnemonic::from_str(s).unwrap());
+        let result = Mnemonic::from_str(result_str).unwrap();
+
+        assert_eq!(result, Mnemonic::xor_all_fast(&shares).unwrap());
+        assert_eq!(
+            Mnemonic::xor_all(&shares).unwrap(),
+            Mnemonic::xor_all_fast(&shares).unwrap()
+        );
+
+        // differing lengths
+        let shares = [
+            "romance wink lottery autumn shop bring dawn tongue range crater truth ability miss spice fitness easy legal release recall obey exchange recycle dragon room",
+            "lion misery divide hurry latin fluid camp advance illegal lab pyramid unaware eager fringe sick camera series number",
+            "vault nominee cradle silk own frown throw leg cactus recall talent wisdom",
+        ]
+        .map(|s| Mnemonic::from_str(s).unwrap());
+        let result = "silent toe meat possible chair blossom wait occur this worth option aware since milk mother grace rocket cement recall obey exchange recycle dragon rocket";
+        assert_eq!(
+            Mnemonic::from_str(result).unwrap(),
+            Mnemonic::xor_all_fast(&shares).unwrap()
+        );
+        assert_eq!(
+            Mnemonic::xor_all(&shares).unwrap(),
+            Mnemonic::xor_all_fast(&shares).unwrap()
+        );
+
+        assert!(Mnemonic::xor_all_fast(&[]).is_err());
+    }
+
+    #[test]
+    fn seed_xor_with_different_lengths_works() {
+        // Coldcard example: https://github.com/Coldcard/firmware/blob/master/docs/seed-xor.md
+        // but truncated mnemonics with correct last word.
+        let str_24 = "romance wink lottery autumn shop bring dawn tongue range crater truth ability miss spice fitness easy legal release recall obey exchange recycle dragon room";
+        let str_16 = "lion misery divide hurry latin fluid camp advance illegal lab pyramid unaware eager fringe sick camera series number";
+        let str_12 = "vault nominee cradle silk own frown throw leg cactus recall talent wisdom";
+        let result_str = "silent toe meat possible chair blossom wait occur this worth option aware since milk mother grace rocket cement recall obey exchange recycle dragon rocket";
+
+        let w_24 = Mnemonic::from_str(str_24).unwrap();
+        let w_16 = Mnemonic::from_str(str_16).unwrap();
+        let w_12 = Mnemonic::from_str(str_12).unwrap();
+        let result = Mnemonic::from_str(result_str).unwrap();
+
+        assert_eq!(result, w_24.clone() ^ w_16.clone() ^ w_12.clone());
+        assert_eq!(result, w_12 ^ w_24 ^ w_16); // Commutative
+    }
+
+    #[test]
+    fn seed_xor_works_12() {
+        // Coldcard example: https://github.com/Coldcard/firmware/blob/master/docs/seed-xor.md
+        let a_str = "romance wink lottery autumn shop bring dawn tongue range crater truth ability";
+        let b_str = "lion misery divide hurry latin fluid camp advance illegal lab pyramid unhappy";
+        let c_str = "vault nominee cradle silk own frown throw leg cactus recall talent wait";
+        let result_str = "silent toe meat possible chair blossom wait occur this worth option boy";
+
+        let a = Mnemonic::from_str(a_str).unwrap();
+        let b = Mnemonic::from_str(b_str).unwrap();
+        let c = Mnemonic::from_str(c_str).unwrap();
+        let result = Mnemonic::from_str(result_str).unwrap();
+
+        assert_eq!(result, a.clone() ^ b.clone() ^ c.clone());
+        assert_eq!(result, b ^ c ^ a); // Commutative
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_electrum_seed() {
+        let electrum_seed =
+            "ramp exotic resource icon sun addict equip sand leisure spare swing tobacco";
+        // ends up with the incorrect checksum
+        let expected = "ramp exotic resource icon sun addict equip sand leisure spare swing toast";
+
+        //let electrum_seed = Mnemonic::from_str(electrum_seed).unwrap();
+        let seed = Mnemonic::from(
+            bip39::Mnemonic::parse_in_normalized_without_checksum_check(
+                Language::English,
+                electrum_seed,
+            )
+            .unwrap(),
+        );
+
+        assert_eq!(electrum_seed, seed.reveal());
+
+        let expected = Mnemonic::from_str(expected).unwrap();
+
+        let split = seed.clone().split().unwrap();
+        println!("1split: '{split:?}'");
+        let result = Mnemonic::xor_all(&split).unwrap();
+        println!("result: '{}'", result);
+        if seed != result {
+            assert_eq!(expected, result);
+        }
+
+        for x in 1..=5 {
+            let split = seed.clone().splitn(x).unwrap();
+            assert_eq!(x, split.len());
+            println!("split: '{split:?}'");
+            let result = Mnemonic::xor_all(&split).unwrap();
+            println!("result: '{}'", result);
+            if seed != result {
+                assert_eq!(expected, result);
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn preserve_bytes_keeps_electrum_seed() {
+        let electrum_seed =
+            "ramp exotic resource icon sun addict equip sand leisure spare swing tobacco";
+        let seed = Mnemonic::parse_normalized_without_checksum_check(electrum_seed).unwrap();
+
+        for x in 1..=5 {
+            let split = seed
+                .clone()
+                .splitn_with_mode(x, RebuildMode::PreserveBytes)
+                .unwrap();
+            assert_eq!(x, split.len());
+            let result = Mnemonic::xor_all_with_mode(&split, RebuildMode::PreserveBytes).unwrap();
+            assert_eq!(seed, result);
+            assert_eq!(electrum_seed, result.reveal());
+        }
+
+        // differing lengths take on the surplus words of the longer one untouched
+        let short = Mnemonic::from_str(
+            "vault nominee cradle silk own frown throw leg cactus recall talent wisdom",
+        )
+        .unwrap();
+        let long = Mnemonic::from_str("romance wink lottery autumn shop bring dawn tongue range crater truth ability miss spice fitness easy legal release recall obey exchange recycle dragon room").unwrap();
+        let xored = short.xor_with_mode(&long, RebuildMode::PreserveBytes);
+        assert_eq!(24, xored.word_count());
+        assert_eq!(
+            long,
+            xored.xor_with_mode(&short, RebuildMode::PreserveBytes)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn preserve_bytes_keeps_language() {
+        let seed: Mnemonic = bip39::Mnemonic::from_entropy_in(Language::French, &[0x42; 16])
+            .unwrap()
+            .into();
+        let split = seed
+            .clone()
+            .splitn_with_mode(3, RebuildMode::PreserveBytes)
+            .unwrap();
+        assert!(split.iter().all(|s| s.language() == Language::French));
+        let result = Mnemonic::xor_all_with_mode(&split, RebuildMode::PreserveBytes).unwrap();
+        assert_eq!(Language::French, result.language());
+        assert_eq!(seed.reveal(), result.reveal());
+        assert_eq!(
+            Err(SeedXorError::EmptyInput),
+            Mnemonic::xor_all_with_mode(&[], RebuildMode::PreserveBytes)
+        );
+    }
+
+    #[test]
+    fn eq_up_to_padding_works() {
+        let mut entropy = [0u8; 32];
+        entropy[0..16].copy_from_slice(&[0xa5; 16]);
+        let w_12 = Mnemonic::from_entropy(&entropy[0..16]).unwrap();
+        let w_24 = Mnemonic::from_entropy(&entropy).unwrap();
+        let w_18 = Mnemonic::from_entropy(&entropy[0..24]).unwrap();
+
+        assert_ne!(w_12, w_24);
+        assert!(w_12.eq_up_to_padding(&w_24));
+        assert!(w_24.eq_up_to_padding(&w_12));
+        assert!(w_18.eq_up_to_padding(&w_24));
+        assert!(w_12.eq_up_to_padding(&w_12));
+
+        entropy[31] = 1;
+        let w_24 = Mnemonic::from_entropy(&entropy).unwrap();
+        assert!(!w_12.eq_up_to_padding(&w_24));
+        assert!(!w_18.eq_up_to_padding(&w_24));
+    }
+
+    #[test]
+    fn word_and_bit_distance() {
+        let mut entropy = [0u8; 16];
+        let zeros = Mnemonic::from_entropy(&entropy).unwrap();
+        entropy[0] = 0x80;
+        let one = Mnemonic::from_entropy(&entropy).unwrap();
+        assert_eq!(1, zeros.bit_distance(&one));
+        // both checksums happen to make the last word about
+        assert_eq!(Ok(1), zeros.word_distance(&one));
+        assert_eq!(Ok(0), one.word_distance(&one));
+        assert_eq!(0, one.bit_distance(&one));
+
+        let french =
+            Mnemonic::from(bip39::Mnemonic::from_entropy_in(Language::French, &entropy).unwrap());
+        assert_eq!(Ok(0), one.word_distance(&french));
+
+        let longer = Mnemonic::from_entropy(&[0xffu8; 32]).unwrap();
+        assert_eq!(255, one.bit_distance(&longer));
+        assert_eq!(
+            Err(SeedXorError::LengthMismatch {
+                expected: 12,
+                got: 24
+            }),
+            one.word_distance(&longer)
+        );
+    }
+
+    #[test]
+    fn ct_eq_matches_eq() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let mut seeds = vec![
+            Mnemonic::from_str(seed).unwrap(),
+            Mnemonic::from_str(seed)
+                .unwrap()
+                .with_word_replaced(0, "abandon")
+                .unwrap(),
+            // checksum bits only
+            Mnemonic::parse_normalized_without_checksum_check(&seed.replace("boy", "bread"))
+                .unwrap(),
+            Mnemonic::from([0u8; 16]),
+            Mnemonic::from([0u8; 32]),
+            Mnemonic::from(bip39::Mnemonic::from_entropy_in(Language::French, &[0; 16]).unwrap()),
+        ];
+        seeds.extend(seeds.clone());
+        for a in &seeds {
+            for b in &seeds {
+                assert_eq!(a == b, a.ct_eq(b), "{a:?} {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn from_entropy_arrays() {
+        let w_24 = Mnemonic::from([0x42u8; 32]);
+        assert_eq!(24, w_24.word_count());
+        assert_eq!(Mnemonic::from_entropy(&[0x42u8; 32]).unwrap(), w_24);
+
+        let w_12 = Mnemonic::from([0x42u8; 16]);
+        assert_eq!(12, w_12.word_count());
+        assert_eq!(Mnemonic::from_entropy(&[0x42u8; 16]).unwrap(), w_12);
+    }
+
+    #[test]
+    fn entropy_vec_roundtrip() {
+        for len in [16, 20, 24, 28, 32] {
+            let entropy: Vec<u8> = (0..len as u8).map(|b| b.wrapping_mul(91)).collect();
+            let seed = Mnemonic::try_from(&entropy[..]).unwrap();
+            assert_eq!(entropy, seed.to_entropy_vec());
+            assert_eq!(len, seed.entropy_bytes());
+        }
+        assert_eq!(
+            Err(Error::BadEntropyBitCount(136).into()),
+            Mnemonic::try_from(&[0u8; 17][..])
+        );
+    }
+
+    #[test]
+    fn entropy_hex() {
+        for len in [16, 20, 24, 28, 32] {
+            let entropy: Vec<u8> = (0..len as u8).map(|b| b.wrapping_mul(37)).collect();
+            let seed = Mnemonic::from_entropy(&entropy).unwrap();
+            let hex = seed.to_entropy_hex();
+            assert_eq!(len * 2, hex.len());
+            assert_eq!(seed, Mnemonic::from_entropy_hex(&hex).unwrap());
+            assert_eq!(
+                seed,
+                Mnemonic::from_entropy_hex(&hex.to_uppercase()).unwrap()
+            );
+        }
+        let seed = Mnemonic::from([0x42u8; 16]);
+        assert_eq!("42".repeat(16), seed.to_entropy_hex());
+
+        assert_eq!(
+            Err(SeedXorError::InvalidHex(3)),
+            Mnemonic::from_entropy_hex("424g4242")
+        );
+        assert_eq!(
+            Err(SeedXorError::InvalidHexLength(31)),
+            Mnemonic::from_entropy_hex(&"4".repeat(31))
+        );
+        assert_eq!(
+            Err(SeedXorError::InvalidHexLength(30)),
+            Mnemonic::from_entropy_hex(&"42".repeat(15))
+        );
+    }
+
+    #[test]
+    fn hex_formatting() {
+        // bip39 test vector
+        let seed = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+        let seed = Mnemonic::from_str(seed).unwrap();
+        assert_eq!("7f".repeat(16), format!("{seed:x}"));
+        assert_eq!("7F".repeat(16), format!("{seed:X}"));
+        assert_eq!(format!("0x{}", "7f".repeat(16)), format!("{seed:#x}"));
+        assert_eq!(seed.to_entropy_hex(), format!("{seed:x}"));
+
+        let seed = Mnemonic::from_entropy(&[0x80u8; 24]).unwrap();
+        assert_eq!("80".repeat(24), format!("{seed:x}"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn binary_formatting() {
+        let seed = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+        let seed = Mnemonic::from_str(seed).unwrap();
+        assert_eq!("01111111".repeat(16), seed.entropy_bit_string());
+        assert_eq!(vec!["01111111"; 16].join(" "), format!("{seed:b}"));
+        assert_eq!(
+            format!("0b{}", vec!["01111111"; 16].join(" ")),
+            format!("{seed:#b}")
+        );
+
+        for words in [12, 15, 18, 21, 24] {
+            let seed = Mnemonic::generate_in(Language::English, words).unwrap();
+            let bits = seed.entropy_bit_string();
+            assert_eq!(seed.entropy_bits(), bits.len());
+            let other = Mnemonic::generate_in(Language::English, words).unwrap();
+            let xored: String = bits
+                .chars()
+                .zip(other.entropy_bit_string().chars())
+                .map(|(a, b)| if a == b { '0' } else { '1' })
+                .collect();
+            assert_eq!(xored, seed.xor(&other).entropy_bit_string());
+        }
+    }
+
+    #[test]
+    fn masked_string() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let seed = Mnemonic::from_str(seed).unwrap();
+        assert_eq!(
+            "silent toe •••• •••• •••• •••• •••• •••• •••• •••• option boy",
+            seed.to_masked_string(2, 2)
+        );
+        assert_eq!(
+            "silent •••• •••• •••• •••• •••• •••• •••• •••• •••• •••• ••••",
+            seed.to_masked_string(1, 0)
+        );
+        assert_eq!(12, seed.to_masked_string(0, 0).split(' ').count());
+        assert_eq!(seed.reveal(), seed.to_masked_string(6, 6));
+        assert_eq!(seed.reveal(), seed.to_masked_string(20, 0));
+    }
+
+    #[test]
+    fn short_string_checked() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let seed = Mnemonic::from_str(seed).unwrap();
+        assert_eq!(Ok(seed.to_short_string()), seed.to_short_string_checked());
+
+        // 4 letter prefixes of this french seed are taken for english
+        let seed = Mnemonic::from(
+            bip39::Mnemonic::from_entropy_in(Language::French, &[0x11u8; 16]).unwrap(),
+        );
+        let short = seed.to_short_string();
+        println!("short: '{short}'");
+        assert_ne!(Ok(seed.reveal()), expand_words(&short));
+        assert_eq!(
+            Err(SeedXorError::AmbiguousShortForm(0)),
+            seed.to_short_string_checked()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn checksum_fix_works() {
+        let electrum_seed =
+            "ramp exotic resource icon sun addict equip sand leisure spare swing tobacco";
+        let fixed = "ramp exotic resource icon sun addict equip sand leisure spare swing toast";
+
+        let seed = Mnemonic::parse_normalized_without_checksum_check(electrum_seed).unwrap();
+        let fixed = Mnemonic::from_str(fixed).unwrap();
+        assert_eq!(fixed, seed.checksum_fix());
+        // already valid seeds are untouched
+        assert_eq!(fixed, fixed.checksum_fix());
+
+        let split = seed.checksum_fix().splitn(3).unwrap();
+        assert_eq!(fixed, Mnemonic::xor_all(&split).unwrap());
+    }
+
+    #[test]
+    fn with_word_replaced_works() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let seed = Mnemonic::from_str(seed).unwrap();
+        assert_eq!(seed, seed.with_word_replaced(2, "meat").unwrap());
+
+        for index in 0..11 {
+            let replaced = seed.with_word_replaced(index, "zoo").unwrap();
+            for (i, (old, new)) in seed.word_iter().zip(replaced.word_iter()).enumerate() {
+                match i {
+                    _ if i == index => assert_eq!("zoo", new),
+                    11 => {} // checksum
+                    _ => assert_eq!(old, new),
+                }
+            }
+        }
+        // the last word keeps only its entropy bits, 7 here, the checksum is recomputed
+        let replaced = seed.with_word_replaced(11, "abandon").unwrap();
+        assert!(seed.word_iter().take(11).eq(replaced.word_iter().take(11)));
+        assert_eq!(
+            0,
+            Language::English
+                .find_word(replaced.word_iter().last().unwrap())
+                .unwrap()
+                >> 4
+        );
+
+        let seed = Mnemonic::from_entropy(&[0xff; 32]).unwrap();
+        let replaced = seed.with_word_replaced(23, "abandon").unwrap();
+        assert!(seed.word_iter().take(23).eq(replaced.word_iter().take(23)));
+        assert_eq!(replaced, Mnemonic::from_str(&replaced.reveal()).unwrap());
+
+        assert_eq!(
+            Err(SeedXorError::IndexOutOfRange { index: 24, len: 24 }),
+            seed.with_word_replaced(24, "abandon")
+        );
+        assert_eq!(
+            Err(Error::UnknownWord(3).into()),
+            seed.with_word_replaced(3, "abandonn")
+        );
+        let french =
+            Mnemonic::from(bip39::Mnemonic::from_entropy_in(Language::French, &[0; 16]).unwrap());
+        assert_eq!(
+            Err(Error::UnknownWord(0).into()),
+            french.with_word_replaced(0, "zoo")
+        );
+        assert_eq!(
+            Language::French,
+            french.with_word_replaced(0, "zoologie").unwrap().language()
+        );
+    }
+
+    #[test]
+    fn from_word_indices() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let long = "romance wink lottery autumn shop bring dawn tongue range crater truth ability miss spice fitness easy legal release recall obey exchange recycle dragon room";
+        for seed in [seed, long] {
+            let seed = Mnemonic::from_str(seed).unwrap();
+            let mut indices: Vec<u16> = seed
+                .word_iter()
+                .map(|w| Language::English.find_word(w).unwrap())
+                .collect();
+            assert_eq!(
+                seed,
+                Mnemonic::from_word_indices_in(Language::English, &indices).unwrap()
+            );
+            indices.swap(0, 1);
+            let swapped: Vec<&str> = indices
+                .iter()
+                .map(|i| Language::English.word_list()[*i as usize])
+                .collect();
+            assert_eq!(
+                Mnemonic::from_str(&swapped.join(" ")).ok(),
+                Mnemonic::from_word_indices_in(Language::English, &indices).ok()
+            );
+        }
+        // every last word agrees with the full parse
+        let mut indices = [0u16; 12];
+        for last in 0..2048 {
+            indices[11] = last;
+            let words = format!(
+                "{} {}",
+                ["abandon"; 11].join(" "),
+                Language::English.word_list()[last as usize]
+            );
+            assert_eq!(
+                bip39::Mnemonic::parse_in_normalized(Language::English, &words)
+                    .map(Mnemonic::from)
+                    .ok(),
+                Mnemonic::from_word_indices_in(Language::English, &indices).ok()
+            );
+        }
+        assert_eq!(
+            Err(Error::InvalidChecksum.into()),
+            Mnemonic::from_word_indices_in(Language::English, &[0; 12])
+        );
+        assert_eq!(
+            Err(Error::BadWordCount(11).into()),
+            Mnemonic::from_word_indices_in(Language::English, &[0; 11])
+        );
+        let mut indices = [0u16; 12];
+        indices[3] = 2048;
+        assert_eq!(
+            Err(SeedXorError::IndexOutOfRange {
+                index: 3,
+                len: 2048
+            }),
+            Mnemonic::from_word_indices_in(Language::English, &indices)
+        );
+    }
+
+    #[test]
+    fn valid_last_words_works() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let words: Vec<&str> = seed.split_whitespace().collect();
+        let last_words = Mnemonic::valid_last_words(&words[0..11]).unwrap();
+        assert_eq!(128, last_words.len());
+        assert!(last_words.contains(&"boy"));
+        for last in last_words {
+            let seed = format!("{} {last}", words[0..11].join(" "));
+            assert!(Mnemonic::from_str(&seed).is_ok(), "{seed}");
+        }
+
+        let seed = "romance wink lottery autumn shop bring dawn tongue range crater truth ability miss spice fitness easy legal release recall obey exchange recycle dragon room";
+        let words: Vec<&str> = seed.split_whitespace().collect();
+        let last_words = Mnemonic::valid_last_words(&words[0..23]).unwrap();
+        assert_eq!(8, last_words.len());
+        assert!(last_words.contains(&"room"));
+
+        assert_eq!(
+            Err(Error::BadWordCount(23).into()),
+            Mnemonic::valid_last_words(&words[0..22])
+        );
+        let mut words = words[0..11].to_vec();
+        words[1] = "winkle";
+        assert_eq!(
+            Err(Error::UnknownWord(1).into()),
+            Mnemonic::valid_last_words(&words)
+        );
+    }
+
+    #[test]
+    fn from_str_strict_works() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        assert_eq!(
+            Mnemonic::from_str(seed).unwrap(),
+            Mnemonic::from_str_strict(seed).unwrap()
+        );
+
+        let short = "sile toe meat poss chai blos wait occu this wort opti boy";
+        assert!(Mnemonic::from_str(short).is_ok());
+        assert_eq!(
+            Err(Error::UnknownWord(0).into()),
+            Mnemonic::from_str_strict(short)
+        );
+
+        for bad in [
+            " silent toe meat possible chair blossom wait occur this worth option boy",
+            "silent toe meat possible chair blossom wait occur this worth option boy ",
+            "silent toe meat possible chair  blossom wait occur this worth option boy",
+            "silent toe meat possible chair\tblossom wait occur this worth option boy",
+            "silent toe meat possible chair blossom wait occur this worth option\nboy",
+            "silent toe meat possible chair Blossom wait occur this worth option boy",
+        ] {
+            assert!(Mnemonic::from_str(bad).is_ok(), "{bad}");
+            assert!(Mnemonic::from_str_strict(bad).is_err(), "{bad}");
+        }
+        assert_eq!(
+            Err(Error::InvalidChecksum.into()),
+            Mnemonic::from_str_strict(
+                "silent toe meat possible chair blossom wait occur this worth option bag"
+            )
+        );
+    }
+
+    #[test]
+    fn prefix_map_matches_expand_words_in() {
+        for language in Language::all() {
+            let map = PrefixMap::new(*language);
+            for word in language.word_list() {
+                for (end, c) in word.char_indices() {
+                    let prefix = &word[..end + c.len_utf8()];
+                    assert_eq!(
+                        expand_words_in(*language, prefix).ok(),
+                        map.get(prefix).map(str::to_owned),
+                        "{language} {prefix}"
+                    );
+                }
+            }
+        }
+        let map = PrefixMap::new(Language::English);
+        let seed = "sile toe meat poss chai blos wai occ this wort opt boy";
+        assert_eq!(
+            expand_words_in(Language::English, seed),
+            expand_words_with_map(&map, seed)
+        );
+        assert_eq!(
+            Err(Error::UnknownWord(1).into()),
+            expand_words_with_map(&map, "act ac")
+        );
+    }
+
+    #[test]
+    fn grid_string() {
+        let seed = "romance wink lottery autumn shop bring dawn tongue range crater truth ability miss spice fitness easy legal release recall obey exchange recycle dragon room";
+        let seed = Mnemonic::from_str(seed).unwrap();
+        assert_eq!(
+            [
+                " 1. romance    2. wink       3. lottery    4. autumn",
+                " 5. shop       6. bring      7. dawn       8. tongue",
+                " 9. range     10. crater    11. truth     12. ability",
+                "13. miss      14. spice     15. fitness   16. easy",
+                "17. legal     18. release   19. recall    20. obey",
+                "21. exchange  22. recycle   23. dragon    24. room",
+            ]
+            .join("\n"),
+            seed.to_grid_string(4)
+        );
+        let seed = Mnemonic::from_str(
+            "silent toe meat possible chair blossom wait occur this worth option boy",
+        )
+        .unwrap();
+        assert_eq!(
+            [
+                " 1. sile   2. toe    3. meat",
+                " 4. poss   5. chai   6. blos",
+                " 7. wait   8. occu   9. this",
+                "10. wort  11. opti  12. boy",
+            ]
+            .join("\n"),
+            seed.to_short_grid_string(3)
+        );
+        assert_eq!(1, seed.to_grid_string(0).lines().count());
+    }
+
+    #[test]
+    fn unique_prefix_string() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let seed = Mnemonic::from_str(seed).unwrap();
+        assert_eq!(
+            "sile toe meat poss chai blos wai occ this wort opt boy",
+            seed.to_unique_prefix_string()
+        );
+        assert_eq!("act", unique_prefix(Language::English, "act"));
+        assert_eq!("zon", unique_prefix(Language::English, "zone"));
+
+        for language in Language::all() {
+            for word in language.word_list() {
+                let prefix = unique_prefix(*language, word);
+                assert_eq!(
+                    Ok(word.to_string()),
+                    expand_words_in(*language, prefix),
+                    "{language}"
+                );
+            }
+            let seed =
+                Mnemonic::from(bip39::Mnemonic::from_entropy_in(*language, &[0x9cu8; 32]).unwrap());
+            let short = seed.to_unique_prefix_string();
+            assert!(short.chars().count() <= seed.to_short_string().chars().count());
+            assert_eq!(
+                seed.reveal(),
+                expand_words_in(*language, &short).unwrap(),
+                "{language}"
+            );
+        }
+    }
+
+    #[test]
+    fn short_string_per_language() {
+        assert_eq!(4, short_prefix_len(Language::English));
+        assert_eq!(1, short_prefix_len(Language::SimplifiedChinese));
+
+        for language in Language::all() {
+            let seed =
+                Mnemonic::from(bip39::Mnemonic::from_entropy_in(*language, &[0x9cu8; 32]).unwrap());
+            let short = seed.to_short_string();
+            assert_eq!(
+                seed.reveal(),
+                expand_words_in(*language, &short).unwrap(),
+                "{language}"
+            );
+        }
+
+        let seed = Mnemonic::from(
+            bip39::Mnemonic::from_entropy_in(Language::Japanese, &[0x21u8; 16]).unwrap(),
+        );
+        let short = seed.to_short_string();
+        assert!(short
+            .split_whitespace()
+            .all(|w| w.chars().count() <= short_prefix_len(Language::Japanese)));
+        assert_eq!(
+            seed.reveal(),
+            expand_words_in(Language::Japanese, &short).unwrap()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn combine_report_xor() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let seed = Mnemonic::from_str(seed).unwrap();
+        let split = seed.clone().splitn(4).unwrap();
+
+        assert_eq!(Ok((seed, vec![])), combine_report(&split));
+        assert!(combine_report(&[]).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn random_works() {
+        let seed = Mnemonic::random_24().unwrap();
+        assert_eq!(
+            (24, Language::English),
+            (seed.word_count(), seed.language())
+        );
+        assert_ne!(seed, Mnemonic::random_24().unwrap());
+        assert_eq!(15, Mnemonic::random(15).unwrap().word_count());
+        assert_eq!(Err(Error::BadWordCount(13).into()), Mnemonic::random(13));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn random_iter_works() {
+        let seeds = Mnemonic::random_iter(Language::English, 24)
+            .take(5)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(5, seeds.len());
+        for seed in &seeds {
+            assert_eq!(24, seed.word_count());
+            assert_eq!(seed, &Mnemonic::from_str(&seed.reveal()).unwrap());
+        }
+        assert_eq!(
+            5,
+            seeds.iter().collect::<std::collections::HashSet<_>>().len()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn search_vanity_works() {
+        let seed = Mnemonic::search_vanity(Language::English, 12, starts_with_word("zoo"), 100_000)
+            .unwrap()
+            .unwrap();
+        assert_eq!(Some("zoo"), seed.word_iter().next());
+        assert!(Mnemonic::from_str(&seed.reveal()).is_ok());
 
-        assert_eq!(result, a.clone() ^ b.clone() ^ c.clone());
-        assert_eq!(result, b ^ c ^ a); // Commutative
+        assert_eq!(
+            Ok(None),
+            Mnemonic::search_vanity(Language::English, 12, |_| false, 10)
+        );
     }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn seed_xor_assignment_works() {
-        // Coldcard example: https://github.com/Coldcard/firmware/blob/master/docs/seed-xor.md
-        let a_str = "romance wink lottery autumn shop bring dawn tongue range crater truth ability miss spice fitness easy legal release recall obey exchange recycle dragon room";
-        let b_str = "lion misery divide hurry latin fluid camp advance illegal lab pyramid unaware eager fringe sick camera series noodle toy crowd jeans select depth lounge";
-        let c_str = "vault nominee cradle silk own frown throw leg cactus recall talent worry gadget surface shy planet purpose coffee drip few seven term squeeze educate";
-        let result_str = "silent toe meat possible chair blossom wait occur this worth option bag nurse find fish scene bench asthma bike wage world quit primary indoor";
+    fn search_vanity_parallel_works() {
+        // about 1 in 2048 * 15, "zoo" then any of the 136 words starting with "a"
+        let predicate = |m: &Mnemonic| {
+            let mut words = m.word_iter();
+            words.next() == Some("zoo") && words.next().is_some_and(|w| w.starts_with('a'))
+        };
+        let seed =
+            Mnemonic::search_vanity_parallel(Language::English, 12, predicate, 4, 10_000_000)
+                .unwrap()
+                .unwrap();
+        assert!(predicate(&seed));
+        assert!(Mnemonic::from_str(&seed.reveal()).is_ok());
 
-        let a = Mnemonic::from_str(a_str).unwrap();
-        let b = Mnemonic::from_str(b_str).unwrap();
-        let c = Mnemonic::from_str(c_str).unwrap();
-        let result = Mnemonic::from_str(result_str).unwrap();
+        let seed = Mnemonic::search_vanity_parallel(
+            Language::English,
+            24,
+            starts_with_word("ab"),
+            0,
+            100_000,
+        )
+        .unwrap()
+        .unwrap();
+        assert!(seed.reveal().starts_with("ab"));
+        assert_eq!(24, seed.word_count());
 
-        let mut assigned = a.xor(&b); // XOR without consuming
-        assigned ^= c;
+        assert_eq!(
+            Ok(None),
+            Mnemonic::search_vanity_parallel(Language::English, 12, |_| false, 3, 10)
+        );
+        assert_eq!(
+            Err(Error::BadWordCount(9).into()),
+            Mnemonic::search_vanity_parallel(Language::English, 9, |_| true, 2, 10)
+        );
+    }
 
-        assert_eq!(result, assigned);
+    #[test]
+    #[cfg(all(
+        feature = "warn-unzeroized",
+        not(feature = "zeroize"),
+        debug_assertions
+    ))]
+    fn warns_unzeroized_once() {
+        use std::sync::atomic::Ordering;
+        for _ in 0..3 {
+            drop(Mnemonic::from([0u8; 16]));
+        }
+        assert_eq!(1, UNZEROIZED_WARNINGS.load(Ordering::Relaxed));
     }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn seed_xor_with_different_lengths_works() {
-        // Coldcard example: https://github.com/Coldcard/firmware/blob/master/docs/seed-xor.md
-        // but truncated mnemonics with correct last word.
-        let str_24 = "romance wink lottery autumn shop bring dawn tongue range crater truth ability miss spice fitness easy legal release recall obey exchange recycle dragon room";
-        let str_16 = "lion misery divide hurry latin fluid camp advance illegal lab pyramid unaware eager fringe sick camera series number";
-        let str_12 = "vault nominee cradle silk own frown throw leg cactus recall talent wisdom";
-        let result_str = "silent toe meat possible chair blossom wait occur this worth option aware since milk mother grace rocket cement recall obey exchange recycle dragon rocket";
+    fn split_2d_works() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let seed = Mnemonic::from_str(seed).unwrap();
 
-        let w_24 = Mnemonic::from_str(str_24).unwrap();
-        let w_16 = Mnemonic::from_str(str_16).unwrap();
-        let w_12 = Mnemonic::from_str(str_12).unwrap();
-        let result = Mnemonic::from_str(result_str).unwrap();
+        for (rows, cols) in [(2, 2), (3, 3), (2, 4), (4, 2), (3, 5), (4, 6)] {
+            let grid = seed.split_2d(rows, cols).unwrap();
+            assert_eq!(rows, grid.len());
+            for row in &grid {
+                assert_eq!(cols, row.len());
+                assert_eq!(seed, Mnemonic::xor_all(row).unwrap());
+            }
+            for c in 0..cols {
+                let column: Vec<Mnemonic> = grid.iter().map(|row| row[c].clone()).collect();
+                assert_eq!(seed, Mnemonic::xor_all(&column).unwrap());
+            }
+        }
 
-        assert_eq!(result, w_24.clone() ^ w_16.clone() ^ w_12.clone());
-        assert_eq!(result, w_12 ^ w_24 ^ w_16); // Commutative
+        for (rows, cols) in [(2, 3), (3, 2), (1, 1), (1, 3), (0, 2)] {
+            assert_eq!(
+                Err(SeedXorError::InvalidGrid { rows, cols }),
+                seed.split_2d(rows, cols)
+            );
+        }
     }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn seed_xor_works_12() {
-        // Coldcard example: https://github.com/Coldcard/firmware/blob/master/docs/seed-xor.md
-        let a_str = "romance wink lottery autumn shop bring dawn tongue range crater truth ability";
-        let b_str = "lion misery divide hurry latin fluid camp advance illegal lab pyramid unhappy";
-        let c_str = "vault nominee cradle silk own frown throw leg cactus recall talent wait";
-        let result_str = "silent toe meat possible chair blossom wait occur this worth option boy";
+    fn split_with_labels_works() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let seed = Mnemonic::from_str(seed).unwrap();
 
-        let a = Mnemonic::from_str(a_str).unwrap();
-        let b = Mnemonic::from_str(b_str).unwrap();
-        let c = Mnemonic::from_str(c_str).unwrap();
-        let result = Mnemonic::from_str(result_str).unwrap();
+        let labels = ["alice", "bank vault", "bob"];
+        let shares = seed.split_with_labels(&labels).unwrap();
+        assert_eq!(
+            labels.to_vec(),
+            shares.iter().map(|s| s.label.as_str()).collect::<Vec<_>>()
+        );
+        let shares: Vec<Mnemonic> = shares.into_iter().map(|s| s.share).collect();
+        assert_eq!(seed, Mnemonic::xor_all(&shares).unwrap());
 
-        assert_eq!(result, a.clone() ^ b.clone() ^ c.clone());
-        assert_eq!(result, b ^ c ^ a); // Commutative
+        assert_eq!(
+            Err(SeedXorError::LengthMismatch {
+                expected: 2,
+                got: 1
+            }),
+            seed.split_with_labels(&["alice"])
+        );
+        assert_eq!(
+            Err(SeedXorError::LengthMismatch {
+                expected: 2,
+                got: 0
+            }),
+            seed.split_with_labels(&[])
+        );
     }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn test_electrum_seed() {
-        let electrum_seed =
-            "ramp exotic resource icon sun addict equip sand leisure spare swing tobacco";
-        // ends up with the incorrect checksum
-        let expected = "ramp exotic resource icon sun addict equip sand leisure spare swing toast";
+    fn shuffle_shares_works() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let seed = Mnemonic::from_str(seed).unwrap();
 
-        //let electrum_seed = Mnemonic::from_str(electrum_seed).unwrap();
-        let seed = Mnemonic::from(
-            bip39::Mnemonic::parse_in_normalized_without_checksum_check(
-                Language::English,
-                electrum_seed,
+        let shares = seed.clone().splitn(6).unwrap();
+        let mut shuffled = shares.clone();
+        let mut state = 0x2545f4914f6cdd1du64;
+        shuffle_shares(&mut shuffled, || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        });
+        assert_ne!(shares, shuffled);
+        assert_eq!(seed, Mnemonic::xor_all(&shuffled).unwrap());
+
+        let mut sorted = shares.clone();
+        sorted.sort();
+        shuffled.sort();
+        assert_eq!(sorted, shuffled);
+
+        shuffle_shares(&mut [], || 0);
+    }
+
+    #[test]
+    fn entropy_with_checksum_bytes() {
+        for (seed, len) in [
+            ("silent toe meat possible chair blossom wait occur this worth option boy", 17),
+            ("romance wink lottery autumn shop bring dawn tongue range crater truth ability miss spice fitness easy legal release recall obey exchange recycle dragon room", 33),
+        ] {
+            let seed = Mnemonic::from_str(seed).unwrap();
+            let bytes = seed.to_entropy_with_checksum_bytes();
+            assert_eq!(len, bytes.len());
+
+            let (entropy, entropy_len) = seed.to_entropy_array();
+            assert_eq!(entropy[0..entropy_len], bytes[0..entropy_len]);
+
+            let bit = |i: usize| (bytes[i / 8] >> (7 - i % 8)) as u16 & 1;
+            let indices: Vec<u16> = (0..seed.word_count())
+                .map(|w| (0..11).fold(0, |idx, b| idx << 1 | bit(w * 11 + b)))
+                .collect();
+            let expected: Vec<u16> = seed
+                .word_iter()
+                .map(|w| Language::English.find_word(w).unwrap())
+                .collect();
+            assert_eq!(expected, indices);
+            // padding bits are zero
+            assert!((seed.word_count() * 11..len * 8).all(|i| bit(i) == 0));
+        }
+    }
+
+    #[test]
+    fn normalize_token_per_language() {
+        for language in Language::all() {
+            assert!(!case_matters(*language));
+            for word in language.word_list() {
+                assert_eq!(*word, normalize_token_in(*language, word));
+                assert_eq!(*word, normalize_token_in(*language, &word.to_uppercase()));
+            }
+        }
+        assert_eq!(
+            "silent toe meat possible chair blossom wait occur this worth option boy",
+            expand_words("Silent TOE meat poss CHAI blOSSom wait occur this worth option boy")
+                .unwrap()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn splitn_cap() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let seed = Mnemonic::from_str(seed).unwrap();
+
+        assert_eq!(
+            Err(SeedXorError::TooManyShares {
+                max: MAX_SPLIT,
+                got: usize::MAX
+            }),
+            seed.clone().splitn(usize::MAX)
+        );
+        assert_eq!(
+            Err(SeedXorError::TooManyShares {
+                max: MAX_SPLIT,
+                got: MAX_SPLIT + 1
+            }),
+            seed.clone()
+                .splitn_with_mode(MAX_SPLIT + 1, RebuildMode::PreserveBytes)
+        );
+        assert_eq!(
+            Err(SeedXorError::TooManyShares { max: 3, got: 4 }),
+            seed.clone().splitn_with_max(4, 3)
+        );
+        assert_eq!(Err(SeedXorError::EmptyInput), seed.clone().splitn(0));
+        let split = seed.clone().splitn_with_max(4, 4).unwrap();
+        assert_eq!(seed, Mnemonic::xor_all(&split).unwrap());
+    }
+
+    #[test]
+    fn to_seed_vectors() {
+        let hex = |seed: [u8; 64]| seed.iter().map(|b| format!("{b:02x}")).collect::<String>();
+        // from the bip39 test vectors, all with passphrase TREZOR
+        for (words, expected) in [
+            (
+                "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+                "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+            ),
+            (
+                "legal winner thank year wave sausage worth useful legal winner thank yellow",
+                "2e8905819b8723fe2c1d161860e5ee1830318dbf49a83bd451cfb8440c28bd6fa457fe1296106559a3c80937a1c1069be3a3a5bd381ee6260e8d9739fce1f607",
+            ),
+        ] {
+            let seed = Mnemonic::from_str(words).unwrap();
+            assert_eq!(expected, hex(seed.to_seed("TREZOR").unwrap()));
+            assert_eq!(expected, hex(seed.to_seed_normalized("TREZOR")));
+        }
+        let seed = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let seed = Mnemonic::from_str(seed).unwrap();
+        assert_eq!(
+            "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4",
+            hex(seed.to_seed("").unwrap())
+        );
+        assert_eq!(
+            Err(SeedXorError::NonAsciiPassphrase(3)),
+            seed.to_seed("pas\u{e9}")
+        );
+    }
+
+    #[test]
+    fn reconstruct_with_passphrase_vector() {
+        // 0x7f and 0x80 entropy xor to 0xff, which is the bip39 test vector
+        // "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong" with passphrase TREZOR
+        let shares = [
+            "legal winner thank year wave sausage worth useful legal winner thank yellow",
+            "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+        ]
+        .map(|s| Mnemonic::from_str(s).unwrap());
+        let seed = reconstruct_with_passphrase(&shares, "TREZOR").unwrap();
+        let expected = "ac27495480225222079d7be181583751e86f571027b0497b5b5d11218e0a8a13332572917f0f8e5a589620c6f15b11c61dee327651a14c34e18231052e48c069";
+        assert_eq!(
+            expected,
+            seed.iter().map(|b| format!("{b:02x}")).collect::<String>()
+        );
+        assert_ne!(seed, reconstruct_with_passphrase(&shares, "").unwrap());
+        assert_eq!(
+            Err(SeedXorError::EmptyInput),
+            reconstruct_with_passphrase(&[], "TREZOR")
+        );
+        assert_eq!(
+            Err(SeedXorError::NonAsciiPassphrase(4)),
+            reconstruct_with_passphrase(&shares, "TREZÖR")
+        );
+    }
+
+    #[test]
+    fn detect_language_works() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        assert_eq!(Ok(Language::English), Mnemonic::detect_language(seed));
+        let japanese = bip39::Mnemonic::from_entropy_in(Language::Japanese, &[7; 16]).unwrap();
+        assert_eq!(
+            Ok(Language::Japanese),
+            Mnemonic::detect_language(&japanese.to_string())
+        );
+
+        // every word is in both wordlists, and the checksum is valid in both
+        let both = "volume innocent science phrase figure lecture intact concert guide fatigue sentence wagon";
+        assert!(bip39::Mnemonic::parse_in_normalized(Language::English, both).is_ok());
+        assert!(bip39::Mnemonic::parse_in_normalized(Language::French, both).is_ok());
+        assert!(matches!(
+            Mnemonic::detect_language(both),
+            Err(SeedXorError::Bip39(Error::AmbiguousLanguages(_)))
+        ));
+        // only valid in one of them
+        let english =
+            "abandon amateur angle animal aspect badge bicycle bonus brave canal capable abandon";
+        assert_eq!(
+            Err(Error::InvalidChecksum),
+            bip39::Mnemonic::parse_in_normalized(Language::French, english)
+        );
+        assert_eq!(Ok(Language::English), Mnemonic::detect_language(english));
+
+        let typo = "silent toe meat possibel chair blossom wait occur this worth option boy";
+        assert_eq!(
+            Err(Error::UnknownWord(3).into()),
+            Mnemonic::detect_language(typo)
+        );
+        assert_eq!(
+            Err(Error::InvalidChecksum.into()),
+            Mnemonic::detect_language(
+                "silent toe meat possible chair blossom wait occur this worth option bag"
             )
-            .unwrap(),
         );
+    }
 
-        assert_eq!(electrum_seed, seed.to_string());
+    #[cfg(feature = "std")]
+    #[test]
+    fn splitn_mixed_works() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let seed = Mnemonic::from_str(seed).unwrap();
 
-        let expected = Mnemonic::from_str(expected).unwrap();
+        let shares = seed.splitn_mixed(&[24, 12]).unwrap();
+        assert_eq!(
+            vec![24, 12],
+            shares.iter().map(|s| s.word_count()).collect::<Vec<_>>()
+        );
+        let combined = Mnemonic::xor_all(&shares).unwrap();
+        assert_eq!(24, combined.word_count());
+        assert!(combined.eq_up_to_padding(&seed));
+        let (entropy, _) = combined.to_entropy_array();
+        assert_eq!(seed, Mnemonic::from_entropy(&entropy[0..16]).unwrap());
 
-        let split = seed.clone().split().unwrap();
-        println!("1split: '{split:?}'");
-        let result = Mnemonic::xor_all(&split).unwrap();
-        println!("result: '{}'", result);
-        if seed != result {
-            assert_eq!(expected, result);
+        for counts in [&[12, 12][..], &[12], &[15, 24, 12, 24, 18]] {
+            let shares = seed.splitn_mixed(counts).unwrap();
+            assert_eq!(counts.len(), shares.len());
+            assert!(Mnemonic::xor_all(&shares).unwrap().eq_up_to_padding(&seed));
         }
+        assert_eq!(
+            seed,
+            Mnemonic::xor_all(&seed.splitn_mixed(&[12, 12, 12]).unwrap()).unwrap()
+        );
 
-        for x in 1..=5 {
-            let split = seed.clone().splitn(x).unwrap();
-            assert_eq!(x, split.len());
-            println!("split: '{split:?}'");
-            let result = Mnemonic::xor_all(&split).unwrap();
-            println!("result: '{}'", result);
-            if seed != result {
-                assert_eq!(expected, result);
-            }
+        let long = Mnemonic::from_str("romance wink lottery autumn shop bring dawn tongue range crater truth ability miss spice fitness easy legal release recall obey exchange recycle dragon room").unwrap();
+        assert_eq!(
+            Err(Error::BadWordCount(12).into()),
+            long.splitn_mixed(&[24, 12])
+        );
+        assert_eq!(
+            Err(Error::BadWordCount(13).into()),
+            seed.splitn_mixed(&[13, 12])
+        );
+        assert_eq!(Err(SeedXorError::EmptyInput), seed.splitn_mixed(&[]));
+    }
+
+    #[test]
+    fn reveal_shows_words() {
+        let words = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let seed = Mnemonic::from_str(words).unwrap();
+        assert_eq!(words, seed.reveal());
+        assert_eq!(words, seed.to_display_string(false));
+
+        #[cfg(feature = "safe-default-display")]
+        {
+            let redacted = format!("Mnemonic(12 words, {})", redacted(&seed));
+            assert_eq!(redacted, seed.to_string());
+            assert_eq!(redacted, format!("{seed:?}"));
+            assert!(!format!("{:?}", seed.clone().split().unwrap()).contains("silent"));
+        }
+        #[cfg(not(feature = "safe-default-display"))]
+        assert_eq!(words, format!("{seed:?}"));
+    }
+
+    #[test]
+    fn checksum_bits() {
+        assert_eq!(
+            vec![4, 5, 6, 7, 8],
+            [12, 15, 18, 21, 24].map(checksum_bits_for_words).to_vec()
+        );
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        assert_eq!(4, Mnemonic::from_str(seed).unwrap().checksum_bits_count());
+        assert_eq!(8, Mnemonic::from([7u8; 32]).checksum_bits_count());
+    }
+
+    #[test]
+    fn entropy_bits_and_bytes() {
+        for (words, bits, checksum) in [
+            (12, 128, 4),
+            (15, 160, 5),
+            (18, 192, 6),
+            (21, 224, 7),
+            (24, 256, 8),
+        ] {
+            let seed = Mnemonic::from_entropy(&vec![0x5a; bits / 8]).unwrap();
+            assert_eq!(words, seed.word_count());
+            assert_eq!(bits, seed.entropy_bits());
+            assert_eq!(bits / 8, seed.entropy_bytes());
+            assert_eq!(checksum, seed.checksum_bits_count());
+            assert_eq!(words * 11, bits + checksum);
+            assert_eq!(
+                Some(seed.entropy_bytes()),
+                entropy_len_from_word_count(words)
+            );
+            assert_eq!(seed.entropy_bytes(), seed.to_entropy_array().1);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn combine_raw_any_works() {
+        let secret = *b"not a bip39 seed";
+        let shares: Vec<String> = Mnemonic::from(secret)
+            .splitn(3)
+            .unwrap()
+            .iter()
+            .map(Mnemonic::reveal)
+            .collect();
+        let shares: Vec<&str> = shares.iter().map(String::as_str).collect();
+        assert_eq!(secret.to_vec(), combine_raw_any(&shares).unwrap());
+
+        // none of these have a valid checksum, nor does the seed they combine to
+        let seed = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon zoo";
+        let seed = Mnemonic::parse_normalized_without_checksum_check(seed).unwrap();
+        let shares: Vec<String> = seed
+            .clone()
+            .splitn_with_mode(2, RebuildMode::PreserveBytes)
+            .unwrap()
+            .iter()
+            .map(Mnemonic::reveal)
+            .collect();
+        let shares: Vec<&str> = shares.iter().map(String::as_str).collect();
+        let mut expected = [0u8; 16];
+        expected[15] = 0x7f;
+        assert_eq!(expected.to_vec(), combine_raw_any(&shares).unwrap());
+
+        // surplus of the longer share is kept
+        let long = [0xaau8; 32];
+        let long = Mnemonic::from(long).reveal();
+        let mut expected = vec![0xaa; 32];
+        expected[0..16]
+            .iter_mut()
+            .zip(secret)
+            .for_each(|(a, b)| *a ^= b);
+        let short = Mnemonic::from(secret).reveal();
+        assert_eq!(expected, combine_raw_any(&[&short, &long]).unwrap());
+
+        assert_eq!(Err(SeedXorError::EmptyInput), combine_raw_any(&[]));
+        assert_eq!(
+            Err(Error::UnknownWord(1).into()),
+            combine_raw_any(&["abandon xyzzy"])
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn entropy_len_word_count() {
+        for (len, words) in [(16, 12), (20, 15), (24, 18), (28, 21), (32, 24)] {
+            assert_eq!(Some(words), word_count_from_entropy_len(len));
+            assert_eq!(Some(len), entropy_len_from_word_count(words));
+            assert_eq!(
+                words,
+                Mnemonic::generate_in(Language::English, words)
+                    .unwrap()
+                    .word_count()
+            );
         }
+        for len in [0, 15, 17, 30, 36] {
+            assert_eq!(None, word_count_from_entropy_len(len));
+        }
+        for words in [0, 3, 11, 13, 27] {
+            assert_eq!(None, entropy_len_from_word_count(words));
+        }
+        for words in [0, 3, 11, 13, 25, usize::MAX] {
+            assert_eq!(
+                Err(Error::BadWordCount(words).into()),
+                Mnemonic::generate_in(Language::English, words)
+            );
+        }
+        assert_eq!(
+            Err(Error::BadEntropyBitCount(136).into()),
+            Mnemonic::from_entropy(&[0; 17])
+        );
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn derive_from_seed() {
         // tl;dr for any seed you can generate a random seed and xor it to "split" it into 2 seeds
@@ -400,6 +3198,24 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn split_stream_works() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let seed = Mnemonic::from_str(seed).unwrap();
+
+        assert_eq!(0, seed.split_stream(0).count());
+        for x in 1..=5 {
+            let split = seed.split_stream(x).collect::<Result<Vec<_>, _>>().unwrap();
+            assert_eq!(x, split.len());
+            assert_eq!(
+                Mnemonic::xor_all(&seed.clone().splitn(x).unwrap()),
+                Mnemonic::xor_all(&split)
+            );
+            assert_eq!(seed, Mnemonic::xor_all(&split).unwrap());
+        }
+    }
+
     #[test]
     fn expand_seed() {
         let orig_seed = "silent toe meat possible chair blossom wait occur this worth option boy";
@@ -445,4 +3261,99 @@ mod tests {
 
         assert_eq!(orig_seed, expand_words(&short_string).unwrap());
     }
+
+    #[test]
+    fn expand_whole_words_and_prefixes() {
+        // whole words that longer words start with
+        assert_eq!(Ok("add addict".to_owned()), expand_words("add addi"));
+        assert_eq!(
+            Ok("win winter wink".to_owned()),
+            expand_words("win wint wink")
+        );
+        // wine, wing, wink, winner, winter and window all start with wi
+        assert_eq!(
+            Err(Error::UnknownWord(1).into()),
+            expand_words_in(Language::English, "win wi")
+        );
+        assert_eq!(
+            Err(Error::UnknownWord(0).into()),
+            expand_words_in(Language::English, "wxyz")
+        );
+        // belette and belote are not next to each other in the French wordlist
+        assert_eq!(
+            Err(Error::UnknownWord(0).into()),
+            expand_words_in(Language::French, "bel")
+        );
+        assert_eq!(
+            Ok("belote".to_owned()),
+            expand_words_in(Language::French, "belo")
+        );
+    }
+
+    #[test]
+    fn combine_entropy_crosslang_works() {
+        let a_entropy = [0x5au8; 16];
+        let b_entropy = [0xc3u8; 16];
+        let xored: Vec<u8> = a_entropy
+            .iter()
+            .zip(b_entropy.iter())
+            .map(|(a, b)| a ^ b)
+            .collect();
+
+        let a = Mnemonic::from(
+            bip39::Mnemonic::from_entropy_in(Language::English, &a_entropy).unwrap(),
+        );
+        let b = Mnemonic::from(
+            bip39::Mnemonic::from_entropy_in(Language::Spanish, &b_entropy).unwrap(),
+        );
+        let expected = bip39::Mnemonic::from_entropy_in(Language::French, &xored).unwrap();
+
+        let result = combine_entropy_crosslang(&[a, b], Language::French).unwrap();
+        assert_eq!(Language::French, result.language());
+        assert_eq!(expected, result.inner);
+
+        assert!(combine_entropy_crosslang(&[], Language::French).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn splitn_verified_works() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let seed = Mnemonic::from_str(seed).unwrap();
+        let shares = seed.clone().splitn_verified(3, MAX_SPLIT, 0).unwrap();
+        assert_eq!(3, shares.len());
+        assert_eq!(Ok(()), seed.verify_roundtrip(&shares));
+
+        // electrum seeds have no valid bip39 checksum, so only the entropy recombines
+        let seed = "ramp exotic resource icon sun addict equip sand leisure spare swing tobacco";
+        let seed = Mnemonic::parse_normalized_without_checksum_check(seed).unwrap();
+        let shares = seed.clone().splitn_verified(3, MAX_SPLIT, 3).unwrap();
+        assert!(seed.verify_roundtrip(&shares).is_err());
+        assert!(Mnemonic::xor_all(&shares).unwrap().eq_up_to_padding(&seed));
+
+        assert_eq!(
+            Err(SeedXorError::TooManyShares { max: 3, got: 4 }),
+            seed.splitn_verified(4, 3, 3)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn verify_roundtrip_works() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let seed = Mnemonic::from_str(seed).unwrap();
+
+        let mut split = seed.clone().splitn(3).unwrap();
+        assert_eq!(Ok(()), seed.verify_roundtrip(&split));
+
+        split[1] = Mnemonic::generate_in(Language::English, 12).unwrap();
+        let err = seed.verify_roundtrip(&split).unwrap_err();
+        assert_eq!(seed, err.expected);
+        assert_eq!(Some(Mnemonic::xor_all(&split).unwrap()), err.actual);
+        // only fingerprints are shown
+        assert!(!format!("{err} {err:?}").contains("silent"));
+
+        let err = seed.verify_roundtrip(&[]).unwrap_err();
+        assert_eq!(None, err.actual);
+    }
 }
@@ -1,5 +1,19 @@
-use seedxor::{expand_words, Language, Mnemonic, SeedXor};
-use std::{process::ExitCode, str::FromStr};
+use seedxor::{
+    expand_words, expand_words_in, expand_words_with_map, qr::QrCode, shuffle_shares, Error,
+    Language, Mnemonic, PrefixMap, SeedXor, SeedXorError, MAX_SPLIT,
+};
+use std::{
+    fs,
+    io::{self, BufRead, Write},
+    ops::ControlFlow,
+    process::{self, ExitCode},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::Instant,
+};
 
 pub struct Args {
     args: Vec<String>,
@@ -39,7 +53,7 @@ impl Args {
                 i += 1;
             }
         }
-        return None;
+        None
     }
     pub fn get_str(&mut self, flags: &[&str], def: &str) -> String {
         match self.get_option(flags) {
@@ -69,22 +83,102 @@ impl Default for Args {
 
 const NUM_SEEDS: usize = 2;
 const WORD_COUNT: usize = 24;
+const MAX_RETRIES: usize = 3;
+const GRID_COLUMNS: usize = 4;
+
+const BENCHMARK_SPLITS: usize = 10_000;
+const BENCHMARK_COMBINE: usize = 1_000;
+const BENCHMARK_SEED: &str = "romance wink lottery autumn shop bring dawn tongue range crater truth ability miss spice fitness easy legal release recall obey exchange recycle dragon room";
+const BENCHMARK_UNSCRAMBLE: [&str; 9] = [
+    "this worth",
+    "option",
+    "chair",
+    "silent toe",
+    "wait",
+    "occur",
+    "boy",
+    "meat possible",
+    "blossom",
+];
+const BENCHMARK_COMPARE: [&str; 7] = [
+    "this worth",
+    "option",
+    "chair blossom",
+    "silent toe",
+    "wait occur",
+    "boy",
+    "meat possible",
+];
 
 fn help(success: bool) -> ExitCode {
     println!(
         r###"usage: seedxor [options...]
  -h, --help                        Display this help
- -s, --split <seed>                Split seed into num-seeds
+ -s, --split <seed>                Split seed into num-seeds, a - reads the seed from the first line of
+                                   stdin
  -n, --num-seeds <num>             Number of seeds to split into or generate
                                    default {NUM_SEEDS}
- -y, --no-validate                 Do not validate a split can be successfully recombined, useful for
-                                   non-bip39 seeds, like ethereum
+ -m, --max-depth <num>             Refuse to split into more than num seeds, guards against typos
+                                   default {MAX_SPLIT}
+ -z, --shuffle-order               With --split, print the seeds in random order
+ -o, --output-dir <path>           With --split, write each seed to its own file share-1.txt to
+                                   share-N.txt in path instead, readable only by you, never
+                                   overwriting a file, with --short also share-N.short.txt of the
+                                   short form
+ -y, --no-validate                 Do not check the bip39 checksum of seeds, a split is only checked to
+                                   recombine to the same entropy, useful for non-bip39 seeds
+     --max-retries <num>           With --split --no-validate, split again up to num times if the
+                                   shares don't recombine, default {MAX_RETRIES}
  -g, --generate                    Generate num-seeds
  -w, --word-count <num>            Number of words to generate in the seed
                                    default {WORD_COUNT}
- -c, --combine <seeds...>          Combine seeds into one seed
- -r, --short                       Display only first 4 letters of seed words
- -u, --unscramble <seed-parts...>  Unscramble seed words in random order to valid seeds
+ -d, --dice <rolls>                With --generate, make one seed from these D6 rolls (digits 1 to 6)
+                                   like a Coldcard does, a - reads them from stdin
+     --coins <flips>               With --generate, make one seed from exactly as many coin flips, H
+                                   or T, as --word-count has bits of entropy, a - reads them from stdin
+ -e, --entropy <hex>               With --generate, make one seed from this entropy instead of random,
+                                   of the length of --word-count if given, a - reads it from stdin
+ -c, --combine <seeds...>          Combine seeds into one seed, a - reads more seeds from stdin, one
+                                   per line
+ -x, --verify-xpub <xpub>          With --combine, only print MATCH or NO MATCH for whether the seed
+                                   derives this account xpub, ypub or zpub, needs the bip32 feature
+     --strict                      With --combine, refuse seeds of different word counts or languages
+                                   instead of extending shorter ones to the longest or warning
+ -r, --short                       Display only first 4 letters of seed words (more for some
+                                   non-english languages)
+     --language <name>             Read and write seeds in this bip39 language instead of detecting it
+                                   from the words, default english for --generate
+     --grid                        Display each seed as numbered words, {GRID_COLUMNS} per row, of the short
+                                   form with --short, a blank line after each to tell them apart
+ -q, --qr                          Display each seed as a QR code instead, of the short form with
+                                   --short, for a terminal with light text on a dark background
+     --json                        Print the seeds of --split, --combine and --unscramble as JSON,
+                                   with both the words and their short form
+ -k, --mask <num>                  Display only the first and last num words of each seed, the others
+                                   as ••••, to check a backup without showing the whole seed
+ -p, --fingerprint                 Display the BIP32 master fingerprint, without passphrase, before
+                                   each seed, needs the bip32 feature
+ -v, --verify <seed> <seeds...>    Only print OK or FAIL for whether seeds combine to seed, a - reads
+                                   more seeds from stdin, seeds of mixed lengths are compared up to
+                                   zero padding, with --no-validate seed and seeds need no checksum
+ -a, --address <seed>              Print the first receive address of BIP44, BIP49 and BIP84 for seed,
+                                   without passphrase, a - reads it from stdin, needs the bitcoin
+                                   feature
+     --path <path>                 With --address, the path to print the address of instead, like
+                                   m/84'/0'/0'/0/1, its purpose 44', 49' or 84' picks the address type
+     --network <name>              With --address, bitcoin or testnet, default bitcoin
+ -u, --unscramble <seed-parts...>  Unscramble seed words in random order to valid seeds, a part like
+                                   3=exchange anchors that word at that position, counted from 1,
+                                   so only the other parts are permuted
+ -f, --fix-checksum                With --unscramble, seed-parts are missing the last (checksum) word,
+                                   print every valid last word for each permutation
+ -t, --threads <num>               With --unscramble, try permutations on num threads, the order
+                                   seeds are printed in then varies, default 1
+     --count                       With --unscramble, only print how many permutations are valid
+     --first                       With --unscramble, stop after printing the first valid seed
+ -l, --last-word <words...>        Print every word that completes words, all but the last word of a
+                                   seed, into a valid seed, one per line
+ -b, --benchmark                   Time {BENCHMARK_SPLITS} splits, a {BENCHMARK_COMBINE} share combine and a 9 part unscramble
         "###
     );
     if success {
@@ -94,102 +188,1150 @@ fn help(success: bool) -> ExitCode {
     }
 }
 
+/// How seeds are displayed, from --short, --mask and --grid.
+#[derive(Clone, Copy, Debug, Default)]
+struct Style {
+    short: bool,
+    mask: Option<usize>,
+    grid: bool,
+}
+
+/// The words of `seed`, or with `short` [Mnemonic::to_short_string], warning on stderr if the
+/// short form won't expand back correctly, or with `mask` only that many words at each end,
+/// see [Mnemonic::to_masked_string], either in a [Mnemonic::to_grid_string] with `grid`.
+fn display(seed: &Mnemonic, style: Style) -> String {
+    if let Some(visible) = style.mask {
+        return seed.to_masked_string(visible, visible);
+    }
+    if style.short && seed.to_short_string_checked().is_err() {
+        eprintln!("# warning: short form of this {} seed may not expand back to the same seed, keep the full words", seed.language());
+    }
+    match (style.short, style.grid) {
+        (true, true) => seed.to_short_grid_string(GRID_COLUMNS),
+        (true, false) => seed.to_short_string(),
+        (false, true) => seed.to_grid_string(GRID_COLUMNS),
+        (false, false) => seed.reveal(),
+    }
+}
+
+/// [display] of `seed`, with `show_fingerprint` after its [fingerprint] and two spaces, like
+/// sha256sum, or on a line of its own above a grid.
+fn seed_line(seed: &Mnemonic, style: Style, show_fingerprint: bool) -> String {
+    let text = display(seed, style);
+    match fingerprint(seed).filter(|_| show_fingerprint) {
+        Some(fingerprint) if style.grid => format!("{fingerprint}\n{text}"),
+        Some(fingerprint) => format!("{fingerprint}  {text}"),
+        None => text,
+    }
+}
+
+/// `s` as a JSON string, quoted and escaped.
+fn json_string(s: &str) -> String {
+    let mut ret = String::with_capacity(s.len() + 2);
+    ret.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => ret.push_str("\\\""),
+            '\\' => ret.push_str("\\\\"),
+            '\n' => ret.push_str("\\n"),
+            c if c.is_control() => ret.push_str(&format!("\\u{:04x}", c as u32)),
+            c => ret.push(c),
+        }
+    }
+    ret.push('"');
+    ret
+}
+
+/// `seed` as a JSON object of its `words` and `short` form, shown like [display] with `mask`,
+/// with its `index` counted from 1 and `fingerprint` if given.
+fn seed_json(
+    seed: &Mnemonic,
+    index: Option<usize>,
+    mask: Option<usize>,
+    show_fingerprint: bool,
+) -> String {
+    let mut ret = String::from("{");
+    if let Some(index) = index {
+        ret.push_str(&format!("\"index\":{index},"));
+    }
+    if let Some(fingerprint) = fingerprint(seed).filter(|_| show_fingerprint) {
+        ret.push_str(&format!("\"fingerprint\":{},", json_string(&fingerprint)));
+    }
+    let style = |short| Style {
+        short,
+        mask,
+        grid: false,
+    };
+    ret.push_str(&format!(
+        "\"words\":{},\"short\":{}}}",
+        json_string(&display(seed, style(false))),
+        json_string(&display(seed, style(true)))
+    ));
+    ret
+}
+
+/// Refuse --json for `mode`, which prints no seeds or only one line.
+fn no_json(mode: &str) -> ExitCode {
+    println!("error: --json is for --split, --combine and --unscramble, not {mode}");
+    ExitCode::FAILURE
+}
+
+/// Print [seed_line] of `seed`, a grid followed by an empty line to tell seeds apart, or with
+/// `qr` a QR code of it, never a grid, after any fingerprint.
+fn print_seed(seed: &Mnemonic, style: Style, qr: bool, show_fingerprint: bool) {
+    if !qr {
+        println!("{}", seed_line(seed, style, show_fingerprint));
+        if style.grid {
+            println!();
+        }
+        return;
+    }
+    let text = display(
+        seed,
+        Style {
+            grid: false,
+            ..style
+        },
+    );
+    let fingerprint = fingerprint(seed).filter(|_| show_fingerprint);
+    if let Some(fingerprint) = fingerprint {
+        println!("{fingerprint}");
+    }
+    match QrCode::encode(text.as_bytes()) {
+        Ok(qr) => println!("{}", qr.to_terminal_string()),
+        Err(e) => println!("error: seed too long for a QR code: {e}"),
+    }
+}
+
+/// Create `dir` and anything missing above it, only accessible to the user on Unix.
+fn create_private_dir(dir: &str) -> io::Result<()> {
+    let mut builder = fs::DirBuilder::new();
+    builder.recursive(true);
+    #[cfg(unix)]
+    std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+    builder.create(dir)
+}
+
+/// Write `text` and a newline to a new file at `path`, only readable by the user on Unix,
+/// failing rather than overwriting an existing file.
+fn write_private_file(path: &std::path::Path, text: &str) -> io::Result<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = options.open(path)?;
+    writeln!(file, "{text}")?;
+    file.sync_all()
+}
+
+/// Print why `seed` is invalid, naming the word of an [Error::UnknownWord], and exit.
+fn exit_invalid(seed: &str, e: SeedXorError) -> ! {
+    match (e, seed.split_whitespace().collect::<Vec<_>>()) {
+        (SeedXorError::Bip39(Error::UnknownWord(i)), words) if i < words.len() => {
+            println!("error: bad word '{}' at position {}", words[i], i + 1)
+        }
+        _ => println!("error: invalid seed: {e}"),
+    }
+    process::exit(1)
+}
+
+/// The bip39 language called `name`, ignoring case, `-` and `_`, like `french` or
+/// `simplified-chinese`, of those seedxor was built with.
+fn parse_language(name: &str) -> Option<Language> {
+    let name = name.replace(['-', '_'], "");
+    Language::all()
+        .iter()
+        .copied()
+        .find(|language| language.to_string().eq_ignore_ascii_case(&name))
+}
+
+/// [expand_words], or with `language` [expand_words_in].
+fn expand(language: Option<Language>, seed: &str) -> Result<String, SeedXorError> {
+    match language {
+        Some(language) => expand_words_in(language, seed),
+        None => expand_words(seed),
+    }
+}
+
+/// `seed` in `language`, if given, as [SeedXor::xor] and parsing entropy always make English.
+fn in_language(seed: Mnemonic, language: Option<Language>) -> Mnemonic {
+    match language {
+        Some(language) if language != seed.language() => {
+            let (entropy, entropy_len) = seed.to_entropy_array();
+            bip39::Mnemonic::from_entropy_in(language, &entropy[0..entropy_len])
+                .expect("entropy of a valid mnemonic")
+                .into()
+        }
+        _ => seed,
+    }
+}
+
+/// The languages of `seeds`, each once in the order they first appear.
+fn languages(seeds: &[Mnemonic]) -> Vec<Language> {
+    let mut ret = Vec::new();
+    for seed in seeds {
+        if !ret.contains(&seed.language()) {
+            ret.push(seed.language());
+        }
+    }
+    ret
+}
+
+/// Parse `seed`, with `no_validate` without checking the bip39 checksum, in `language` or the
+/// one it's detected as.
+fn parse(
+    seed: &str,
+    no_validate: bool,
+    language: Option<Language>,
+) -> Result<Mnemonic, SeedXorError> {
+    match language {
+        Some(language) if no_validate => {
+            Mnemonic::parse_in_normalized_without_checksum_check(language, seed)
+        }
+        Some(language) => Ok(bip39::Mnemonic::parse_in_normalized(
+            language,
+            &expand_words_in(language, seed)?,
+        )?
+        .into()),
+        None if no_validate => Mnemonic::parse_normalized_without_checksum_check(seed),
+        None => Mnemonic::from_str(seed),
+    }
+}
+
+/// [parse] `seed`, exiting with [exit_invalid] if it doesn't parse.
+fn parse_seed(seed: &str, no_validate: bool, language: Option<Language>) -> Mnemonic {
+    parse(seed, no_validate, language).unwrap_or_else(|e| exit_invalid(seed, e))
+}
+
+/// Parse every seed of `args`, where a - is every non empty line of stdin.
+fn read_seeds(args: Vec<String>, no_validate: bool, language: Option<Language>) -> Vec<Mnemonic> {
+    let mut ret = Vec::with_capacity(args.len());
+    for s in args {
+        if s == "-" {
+            for line in io::stdin().lock().lines() {
+                let line = line.expect("cannot read stdin");
+                if !line.trim().is_empty() {
+                    ret.push(parse_seed(line.trim(), no_validate, language));
+                }
+            }
+        } else {
+            ret.push(parse_seed(&s, no_validate, language));
+        }
+    }
+    ret
+}
+
+/// Seed parts to unscramble, with the word indices for checking each order of them quickly.
+struct Scrambled<'a> {
+    parts: &'a [String],
+    /// Words that stay at their position, counted from 0, sorted by position.
+    anchors: &'a [(usize, String)],
+    fix_checksum: bool,
+    /// Prefixes of the language given with --language or detected from the parts, for
+    /// [check_permutation], [None] to detect it for every order.
+    map: Option<PrefixMap>,
+    /// Language and word indices of each part, then of the anchors, for
+    /// [Mnemonic::from_word_indices_in], [None] to fully parse every order with
+    /// [check_permutation] instead.
+    indices: Option<(Language, Vec<Vec<u16>>, Vec<u16>)>,
+}
+
+impl<'a> Scrambled<'a> {
+    fn new(
+        parts: &'a [String],
+        anchors: &'a [(usize, String)],
+        fix_checksum: bool,
+        language: Option<Language>,
+    ) -> Self {
+        let words: Vec<&str> = parts
+            .iter()
+            .chain(anchors.iter().map(|(_, word)| word))
+            .map(String::as_str)
+            .collect();
+        // the language FromStr parses every order of the parts in, they all have the same words
+        let language = language.or_else(|| bip39::Mnemonic::language_of(words.join(" ")).ok());
+        let indices = language.filter(|_| !fix_checksum).and_then(|language| {
+            let parts = parts
+                .iter()
+                .map(|part| {
+                    part.split_whitespace()
+                        .map(|w| language.find_word(w))
+                        .collect()
+                })
+                .collect::<Option<_>>()?;
+            let anchors = anchors
+                .iter()
+                .map(|(_, word)| language.find_word(word))
+                .collect::<Option<_>>()?;
+            Some((language, parts, anchors))
+        });
+        Scrambled {
+            parts,
+            anchors,
+            fix_checksum,
+            map: language.map(PrefixMap::new),
+            indices,
+        }
+    }
+
+    /// The parts in `order` with the anchors in place, separated by spaces.
+    fn join(&self, order: &[usize]) -> String {
+        let mut words: Vec<&str> = order
+            .iter()
+            .flat_map(|i| self.parts[*i].split_whitespace())
+            .collect();
+        for (position, word) in self.anchors {
+            words.insert(*position, word);
+        }
+        words.join(" ")
+    }
+
+    /// Check the parts in `order` like [check_permutation], only joining them into words for
+    /// valid mnemonics, `buf` is reused between calls to not allocate either.
+    fn check(
+        &self,
+        order: &[usize],
+        buf: &mut Vec<u16>,
+        found: &mut impl FnMut(&str, Mnemonic) -> ControlFlow<()>,
+    ) -> ControlFlow<(), bool> {
+        let (language, indices, anchors) = match &self.indices {
+            Some(indices) => indices,
+            None => {
+                return check_permutation(
+                    &self.join(order),
+                    self.fix_checksum,
+                    self.map.as_ref(),
+                    found,
+                )
+            }
+        };
+        buf.clear();
+        for i in order {
+            buf.extend_from_slice(&indices[*i]);
+        }
+        for ((position, _), index) in self.anchors.iter().zip(anchors) {
+            buf.insert(*position, *index);
+        }
+        match Mnemonic::from_word_indices_in(*language, buf) {
+            Ok(mnemonic) => {
+                found(&self.join(order), mnemonic)?;
+                ControlFlow::Continue(true)
+            }
+            Err(_) => ControlFlow::Continue(false),
+        }
+    }
+}
+
+/// Parse `words` with [expand_words_with_map], or [Mnemonic::from_str] without `map`.
+fn parse_with_map(words: &str, map: Option<&PrefixMap>) -> Result<Mnemonic, SeedXorError> {
+    match map {
+        Some(map) => Ok(bip39::Mnemonic::parse_in_normalized(
+            map.language(),
+            &expand_words_with_map(map, words)?,
+        )?
+        .into()),
+        None => Mnemonic::from_str(words),
+    }
+}
+
+/// Check the permutation `words`, calling `found` with each valid mnemonic, returns whether it
+/// counts as valid, or [ControlFlow::Break] as soon as `found` does.
+/// With `fix_checksum` the words are missing the last word and every valid last word is tried.
+/// The words are in the language of `map`, or the one they're detected as.
+fn check_permutation(
+    words: &str,
+    fix_checksum: bool,
+    map: Option<&PrefixMap>,
+    found: &mut impl FnMut(&str, Mnemonic) -> ControlFlow<()>,
+) -> ControlFlow<(), bool> {
+    if fix_checksum {
+        let split: Vec<&str> = words.split_whitespace().collect();
+        let last_words = match map {
+            Some(map) => Mnemonic::valid_last_words_in(map.language(), &split),
+            None => Mnemonic::valid_last_words(&split),
+        };
+        let last_words = match last_words {
+            Ok(last_words) => last_words,
+            Err(_) => return ControlFlow::Continue(false),
+        };
+        for last in &last_words {
+            let words = format!("{words} {last}");
+            if let Ok(mnemonic) = parse_with_map(&words, map) {
+                found(&words, mnemonic)?;
+            }
+        }
+        ControlFlow::Continue(true)
+    } else if let Ok(mnemonic) = parse_with_map(words, map) {
+        found(words, mnemonic)?;
+        ControlFlow::Continue(true)
+    } else {
+        ControlFlow::Continue(false)
+    }
+}
+
+/// Try every permutation of the parts, calling `found` with each valid mnemonic, see
+/// [Scrambled::check], until `found` breaks.
+/// Returns how many permutations were tried and how many of those were valid.
+fn unscramble(
+    scrambled: &Scrambled,
+    mut found: impl FnMut(&str, Mnemonic) -> ControlFlow<()>,
+) -> (u64, u64) {
+    let mut order: Vec<usize> = (0..scrambled.parts.len()).collect();
+    let mut heap = permutohedron::Heap::new(&mut order);
+    let mut buf = Vec::new();
+    let (mut tried, mut good) = (0u64, 0u64);
+    while let Some(order) = heap.next_permutation() {
+        tried += 1;
+        match scrambled.check(order, &mut buf, &mut found) {
+            ControlFlow::Continue(valid) => good += valid as u64,
+            ControlFlow::Break(()) => return (tried, good + 1),
+        }
+    }
+    (tried, good)
+}
+
+/// [unscramble] split across `threads`, each taking every permutation that starts with one
+/// part at a time, so `found` is called in no particular order, and once it breaks every
+/// thread stops.
+fn unscramble_parallel(
+    scrambled: &Scrambled,
+    threads: usize,
+    found: impl Fn(&str, Mnemonic) -> ControlFlow<()> + Sync,
+) -> (u64, u64) {
+    let len = scrambled.parts.len();
+    let next = AtomicUsize::new(0);
+    let done = AtomicBool::new(false);
+    let worker = || {
+        let mut found = |words: &str, mnemonic| found(words, mnemonic);
+        let mut buf = Vec::new();
+        let mut order = Vec::with_capacity(len);
+        let (mut tried, mut good) = (0u64, 0u64);
+        loop {
+            let first = next.fetch_add(1, Ordering::Relaxed);
+            if first >= len {
+                break (tried, good);
+            }
+            let mut rest: Vec<usize> = (0..len).filter(|i| *i != first).collect();
+            let mut heap = permutohedron::Heap::new(&mut rest);
+            while let Some(rest) = heap.next_permutation() {
+                if done.load(Ordering::Relaxed) {
+                    return (tried, good);
+                }
+                order.clear();
+                order.push(first);
+                order.extend_from_slice(rest);
+                tried += 1;
+                match scrambled.check(&order, &mut buf, &mut found) {
+                    ControlFlow::Continue(valid) => good += valid as u64,
+                    ControlFlow::Break(()) => {
+                        done.store(true, Ordering::Relaxed);
+                        return (tried, good + 1);
+                    }
+                }
+            }
+        }
+    };
+    std::thread::scope(|s| {
+        let workers: Vec<_> = (0..threads.max(1)).map(|_| s.spawn(worker)).collect();
+        workers
+            .into_iter()
+            .map(|worker| worker.join().expect("unscramble thread panicked"))
+            .fold((0, 0), |(tried, good), worker| {
+                (tried + worker.0, good + worker.1)
+            })
+    })
+}
+
+#[cfg(feature = "bip32")]
+fn verify_xpub(seed: &Mnemonic, xpub: &str) -> ExitCode {
+    match seed.verify_xpub("", xpub) {
+        Ok(true) => {
+            println!("MATCH");
+            ExitCode::SUCCESS
+        }
+        Ok(false) => {
+            println!("NO MATCH");
+            ExitCode::FAILURE
+        }
+        Err(e) => {
+            println!("error: invalid xpub: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(not(feature = "bip32"))]
+fn verify_xpub(_: &Mnemonic, _: &str) -> ExitCode {
+    println!("error: --verify-xpub needs seedxor built with the bip32 feature");
+    ExitCode::FAILURE
+}
+
+/// Print each `path` of `seed` and its address on `network`, by default the first receive
+/// address of BIP44, BIP49 and BIP84.
+#[cfg(feature = "bitcoin")]
+fn print_addresses(seed: &Mnemonic, path: Option<String>, network: Option<String>) -> ExitCode {
+    use seedxor::address::Network;
+    let network = match network.as_deref().map(str::to_lowercase).as_deref() {
+        None | Some("bitcoin" | "mainnet") => Network::Bitcoin,
+        Some("testnet") => Network::Testnet,
+        Some(_) => {
+            println!(
+                "error: unknown network '{}', supported: bitcoin, testnet",
+                network.unwrap_or_default()
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+    let paths = match path {
+        Some(path) => vec![path],
+        None => [44, 49, 84]
+            .iter()
+            .map(|purpose| format!("m/{purpose}'/{}'/0'/0/0", network.coin_type()))
+            .collect(),
+    };
+    for path in paths {
+        match seed.derive_address(&path, network) {
+            Ok(address) => println!("{path}  {address}"),
+            Err(SeedXorError::InvalidPath(i)) => {
+                let part = path.split('/').nth(i).unwrap_or_default();
+                println!(
+                    "error: bad part '{part}' at position {} of path '{path}'",
+                    i + 1
+                );
+                return ExitCode::FAILURE;
+            }
+            Err(e) => {
+                println!("error: invalid path '{path}': {e}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(not(feature = "bitcoin"))]
+fn print_addresses(_: &Mnemonic, _: Option<String>, _: Option<String>) -> ExitCode {
+    println!("error: --address needs seedxor built with the bitcoin feature");
+    ExitCode::FAILURE
+}
+
+/// The BIP32 master fingerprint of `seed` without a passphrase as hex, [None] without the
+/// bip32 feature.
+#[cfg(feature = "bip32")]
+fn fingerprint(seed: &Mnemonic) -> Option<String> {
+    Some(
+        seed.fingerprint("")
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect(),
+    )
+}
+
+#[cfg(not(feature = "bip32"))]
+fn fingerprint(_: &Mnemonic) -> Option<String> {
+    None
+}
+
+fn benchmark() -> ExitCode {
+    let seed = Mnemonic::from_str(BENCHMARK_SEED).expect("invalid bip39 mnemonic");
+    let start = Instant::now();
+    for _ in 0..BENCHMARK_SPLITS {
+        let parts = seed.split().expect("could not split mnemonic");
+        if let Err(e) = seed.verify_roundtrip(&parts) {
+            println!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    }
+    eprintln!(
+        "# {BENCHMARK_SPLITS} splits/combines: {:?}",
+        start.elapsed()
+    );
+
+    let parts = seed
+        .clone()
+        .splitn(BENCHMARK_COMBINE)
+        .expect("could not split mnemonic");
+    let start = Instant::now();
+    let fold = Mnemonic::xor_all(&parts);
+    let fold_elapsed = start.elapsed();
+    let start = Instant::now();
+    let fast = Mnemonic::xor_all_fast(&parts);
+    let fast_elapsed = start.elapsed();
+    if fold != fast || fast.as_ref() != Ok(&seed) {
+        println!("error: xor_all and xor_all_fast disagree");
+        return ExitCode::FAILURE;
+    }
+    eprintln!("# combine {BENCHMARK_COMBINE} shares: xor_all {fold_elapsed:?}, xor_all_fast {fast_elapsed:?}");
+
+    let parts: Vec<String> = BENCHMARK_UNSCRAMBLE.iter().map(|s| s.to_string()).collect();
+    let total: u128 = (1..=parts.len() as u128).product();
+    let scrambled = Scrambled::new(&parts, &[], false, None);
+    let start = Instant::now();
+    let (_, good) = unscramble(&scrambled, |_, _| ControlFlow::Continue(()));
+    eprintln!(
+        "# unscramble of {total} permutations: {:?}, good: {good}",
+        start.elapsed()
+    );
+    let start = Instant::now();
+    let (tried, _) = unscramble(&scrambled, |_, _| ControlFlow::Break(()));
+    eprintln!(
+        "# unscramble to the first valid seed: {:?}, tried: {tried}",
+        start.elapsed()
+    );
+
+    // parsing every candidate is far slower, so compare the three ways on fewer parts
+    let parts: Vec<String> = BENCHMARK_COMPARE.iter().map(|s| s.to_string()).collect();
+    let total: u128 = (1..=parts.len() as u128).product();
+    let scrambled = Scrambled::new(&parts, &[], false, None);
+    let start = Instant::now();
+    let (_, good) = unscramble(&scrambled, |_, _| ControlFlow::Continue(()));
+    let fast_elapsed = start.elapsed();
+    let mapped = Scrambled {
+        indices: None,
+        ..Scrambled::new(&parts, &[], false, None)
+    };
+    let start = Instant::now();
+    let (_, mapped_good) = unscramble(&mapped, |_, _| ControlFlow::Continue(()));
+    let mapped_elapsed = start.elapsed();
+    let parsed = Scrambled {
+        map: None,
+        ..mapped
+    };
+    let start = Instant::now();
+    let (_, parsed_good) = unscramble(&parsed, |_, _| ControlFlow::Continue(()));
+    let parsed_elapsed = start.elapsed();
+    if good != parsed_good || good != mapped_good {
+        println!("error: unscramble by word indices and by parsing disagree");
+        return ExitCode::FAILURE;
+    }
+    eprintln!("# unscramble of {total} permutations: parsed {parsed_elapsed:?}, prefix map {mapped_elapsed:?}, word indices {fast_elapsed:?}, good: {good}");
+    ExitCode::SUCCESS
+}
+
 fn main() -> ExitCode {
     let mut args = Args::default();
 
     let short = args.flags(&["-r", "--short"]);
-    let num_seeds = args.get(&["-n", "--num-seeds"], NUM_SEEDS);
+    let qr = args.flags(&["-q", "--qr"]);
+    let mask = match args
+        .get_option(&["-k", "--mask"])
+        .map(|visible| visible.parse())
+    {
+        Some(Ok(visible)) => Some(visible),
+        Some(Err(_)) => {
+            println!("error: invalid --mask");
+            return help(false);
+        }
+        None => None,
+    };
+    let grid = args.flags(&["--grid"]);
+    let style = Style { short, mask, grid };
+    let show_fingerprint = args.flags(&["-p", "--fingerprint"]);
+    let json = args.flags(&["--json"]);
+    let language = match args.get_option(&["--language"]) {
+        Some(name) => match parse_language(&name) {
+            Some(language) => Some(language),
+            None => {
+                let supported: Vec<String> = Language::all()
+                    .iter()
+                    .map(|l| l.to_string().to_lowercase())
+                    .collect();
+                println!(
+                    "error: unknown language '{name}', supported: {}",
+                    supported.join(", ")
+                );
+                return ExitCode::FAILURE;
+            }
+        },
+        None => None,
+    };
+    if show_fingerprint && !cfg!(feature = "bip32") {
+        println!("error: --fingerprint needs seedxor built with the bip32 feature");
+        return ExitCode::FAILURE;
+    }
+    // explicit, so --generate --entropy can refuse more than one seed
+    let num_seeds_arg = args
+        .get_option(&["-n", "--num-seeds"])
+        .map(|n| n.parse().unwrap_or(NUM_SEEDS));
+    let num_seeds = num_seeds_arg.unwrap_or(NUM_SEEDS);
     if num_seeds < 1 {
         println!("error: num-seeds must be > 1");
         return help(false);
     } else if args.flags(&["-h", "--help"]) {
         return help(true);
+    } else if args.flags(&["-b", "--benchmark"]) {
+        return benchmark();
     } else if args.flags(&["-s", "--split"]) {
         let no_validate = args.flags(&["-y", "--no-validate"]);
+        let shuffle_order = args.flags(&["-z", "--shuffle-order"]);
+        let output_dir = args.get_option(&["-o", "--output-dir"]);
+        let max_depth = args.get(&["-m", "--max-depth"], MAX_SPLIT);
+        let max_retries = args.get(&["--max-retries"], MAX_RETRIES);
         let remaining = args.remaining();
         if remaining.len() != 1 {
             println!("remaining: {remaining:?}");
             println!("error: --split needs exactly 1 seed argument");
             return help(false);
         }
-        let seed = &remaining[0];
-        let seed: Mnemonic = if no_validate {
-            Mnemonic::parse_normalized_without_checksum_check(seed).expect("invalid mnemonic")
+        let mut seed = remaining[0].clone();
+        if seed == "-" {
+            seed.clear();
+            io::stdin()
+                .lock()
+                .read_line(&mut seed)
+                .expect("cannot read stdin");
+            if seed.trim().is_empty() {
+                println!("error: --split - needs a seed on stdin");
+                return ExitCode::FAILURE;
+            }
+        }
+        let seed = seed.trim();
+        let seed = parse_seed(seed, no_validate, language);
+        if num_seeds > max_depth {
+            println!("error: num-seeds {num_seeds} is more than max-depth {max_depth}, raise --max-depth if you really mean it");
+            return ExitCode::FAILURE;
+        }
+        let parts = if no_validate {
+            seed.clone()
+                .splitn_verified(num_seeds, max_depth, max_retries)
         } else {
-            Mnemonic::from_str(seed).expect("invalid bip39 mnemonic")
+            seed.clone().splitn_with_max(num_seeds, max_depth)
+        };
+        let mut parts = match parts {
+            Ok(parts) => parts,
+            Err(e) => {
+                println!("error: {e}");
+                return ExitCode::FAILURE;
+            }
         };
-        let parts = seed
-            .clone()
-            .splitn(num_seeds)
-            .expect("could not split mnemonic");
         if !no_validate {
-            let result = Mnemonic::xor_all(&parts).unwrap();
-            if result != seed {
-                panic!("error: result != seed, '{result}' != '{seed}'");
+            // shares always combine to English
+            let english = in_language(seed.clone(), Some(Language::English));
+            if let Err(e) = english.verify_roundtrip(&parts) {
+                println!("error: {e}");
+                return ExitCode::FAILURE;
             }
         }
+        if shuffle_order {
+            shuffle_shares(&mut parts, || {
+                let mut buf = [0u8; 8];
+                getrandom::getrandom(&mut buf).expect("cannot get randomness");
+                u64::from_le_bytes(buf)
+            });
+        }
+        if let Some(output_dir) = output_dir {
+            if qr || json || mask.is_some() {
+                println!(
+                    "error: --output-dir writes the words of each seed, without --qr, --json or --mask"
+                );
+                return ExitCode::FAILURE;
+            }
+            if let Err(e) = create_private_dir(&output_dir) {
+                println!("error: cannot create {output_dir}: {e}");
+                return ExitCode::FAILURE;
+            }
+            for (i, part) in parts.into_iter().enumerate() {
+                let part = in_language(part, language);
+                let mut files = vec![(
+                    format!("share-{}.txt", i + 1),
+                    Style {
+                        short: false,
+                        ..style
+                    },
+                )];
+                if short {
+                    files.push((format!("share-{}.short.txt", i + 1), style));
+                }
+                for (name, style) in files {
+                    let path = std::path::Path::new(&output_dir).join(name);
+                    let line = seed_line(&part, style, show_fingerprint);
+                    if let Err(e) = write_private_file(&path, &line) {
+                        println!("error: cannot write {}: {e}", path.display());
+                        return ExitCode::FAILURE;
+                    }
+                    eprintln!("# wrote {}", path.display());
+                }
+            }
+            return ExitCode::SUCCESS;
+        }
+        if json {
+            let parts: Vec<String> = parts
+                .into_iter()
+                .enumerate()
+                .map(|(i, part)| {
+                    seed_json(
+                        &in_language(part, language),
+                        Some(i + 1),
+                        mask,
+                        show_fingerprint,
+                    )
+                })
+                .collect();
+            println!("[{}]", parts.join(","));
+            return ExitCode::SUCCESS;
+        }
         for part in parts {
-            println!("{}", part.to_display_string(short));
+            print_seed(&in_language(part, language), style, qr, show_fingerprint);
         }
     } else if args.flags(&["-g", "--generate"]) {
-        let word_count = args.get(&["-w", "--word-count"], WORD_COUNT);
+        if json {
+            return no_json("--generate");
+        }
+        let word_count_arg = args
+            .get_option(&["-w", "--word-count"])
+            .map(|w| w.parse().unwrap_or(WORD_COUNT));
+        let word_count = word_count_arg.unwrap_or(WORD_COUNT);
+        let dice = args.get_option(&["-d", "--dice"]);
+        let entropy = args.get_option(&["-e", "--entropy"]);
+        let coins = args.get_option(&["--coins"]);
         if !args.remaining().is_empty() {
             println!("error: --generate needs 0 arguments");
             return help(false);
         }
+        if let Some(mut entropy) = entropy {
+            if dice.is_some() || coins.is_some() || num_seeds_arg.is_some_and(|n| n > 1) {
+                println!("error: --entropy makes exactly one seed, without --dice, --coins or --num-seeds");
+                return help(false);
+            }
+            if entropy == "-" {
+                entropy.clear();
+                io::stdin()
+                    .lock()
+                    .read_line(&mut entropy)
+                    .expect("cannot read stdin");
+            }
+            let seed = match Mnemonic::from_entropy_hex(&entropy) {
+                Ok(seed) => in_language(seed, language),
+                Err(e) => {
+                    println!("error: invalid entropy: {e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            if let Some(word_count) = word_count_arg.filter(|w| *w != seed.word_count()) {
+                println!(
+                    "error: entropy of {} bytes makes {} words, not --word-count {word_count}",
+                    seed.entropy_bytes(),
+                    seed.word_count()
+                );
+                return ExitCode::FAILURE;
+            }
+            print_seed(&seed, style, qr, show_fingerprint);
+            return ExitCode::SUCCESS;
+        }
+        if let Some(mut coins) = coins {
+            if dice.is_some() {
+                println!("error: --coins makes one seed from coin flips, without --dice");
+                return help(false);
+            }
+            if coins == "-" {
+                coins.clear();
+                io::stdin()
+                    .lock()
+                    .read_line(&mut coins)
+                    .expect("cannot read stdin");
+            }
+            let flips: Vec<char> = coins.chars().filter(|c| !c.is_whitespace()).collect();
+            if let Some(i) = flips
+                .iter()
+                .position(|c| !matches!(c, 'H' | 'h' | 'T' | 't'))
+            {
+                println!(
+                    "error: coin flip '{}' at position {} is not H or T",
+                    flips[i],
+                    i + 1
+                );
+                return ExitCode::FAILURE;
+            }
+            let flips: Vec<bool> = flips.iter().map(|c| matches!(c, 'H' | 'h')).collect();
+            return match Mnemonic::from_coin_flips(&flips, word_count) {
+                Ok(seed) => {
+                    print_seed(&in_language(seed, language), style, qr, show_fingerprint);
+                    ExitCode::SUCCESS
+                }
+                Err(SeedXorError::LengthMismatch { expected, got }) => {
+                    println!(
+                        "error: {word_count} words need exactly {expected} coin flips, got {got}"
+                    );
+                    ExitCode::FAILURE
+                }
+                Err(e) => {
+                    println!("error: invalid coin flips: {e}");
+                    ExitCode::FAILURE
+                }
+            };
+        }
+        if let Some(mut dice) = dice {
+            if dice == "-" {
+                dice.clear();
+                io::stdin()
+                    .lock()
+                    .read_line(&mut dice)
+                    .expect("cannot read stdin");
+            }
+            let rolls: Vec<u8> = dice
+                .bytes()
+                .filter(|b| !b.is_ascii_whitespace())
+                .map(|b| b.wrapping_sub(b'0'))
+                .collect();
+            return match Mnemonic::from_dice(&rolls, word_count) {
+                Ok(seed) => {
+                    print_seed(&in_language(seed, language), style, qr, show_fingerprint);
+                    ExitCode::SUCCESS
+                }
+                Err(e) => {
+                    println!("error: invalid dice rolls: {e}");
+                    ExitCode::FAILURE
+                }
+            };
+        }
         for _ in 0..num_seeds {
-            println!(
-                "{}",
-                Mnemonic::generate_in(Language::English, word_count)
-                    .expect("cannot generate seed")
-                    .to_display_string(short)
-            );
+            match Mnemonic::generate_in(language.unwrap_or(Language::English), word_count) {
+                Ok(seed) => print_seed(&seed, style, qr, show_fingerprint),
+                Err(e) => {
+                    println!("error: cannot generate seed: {e}");
+                    return ExitCode::FAILURE;
+                }
+            }
         }
     } else if args.flags(&["-c", "--combine"]) {
+        let xpub = args.get_option(&["-x", "--verify-xpub"]);
+        let strict = args.flags(&["--strict"]);
         let remaining = args.remaining();
         if remaining.is_empty() {
             println!("error: --combine needs > 0 arguments");
             return help(false);
         }
-        let parts: Vec<Mnemonic> = remaining
-            .into_iter()
-            .map(|s| Mnemonic::from_str(&s).expect("invalid bip39 mnemonic"))
-            .collect();
-        let seed = Mnemonic::xor_all(&parts).unwrap();
-        println!("{}", seed.to_display_string(short));
+        let parts = read_seeds(remaining, false, language);
+        let languages = languages(&parts);
+        if languages.len() > 1 {
+            let names: Vec<String> = languages.iter().map(Language::to_string).collect();
+            if strict {
+                println!(
+                    "error: cannot combine seeds of different languages: {}",
+                    names.join(", ")
+                );
+                return ExitCode::FAILURE;
+            }
+            eprintln!(
+                "# warning: combining seeds of different languages ({}), the result is {}",
+                names.join(", "),
+                language.unwrap_or(Language::English)
+            );
+        }
+        let seed = if strict {
+            Mnemonic::xor_all_strict(&parts)
+        } else {
+            Mnemonic::xor_all(&parts)
+        };
+        let seed = match seed {
+            Ok(seed) => in_language(seed, language),
+            Err(e) => {
+                println!("error: cannot combine seeds: {e}");
+                return ExitCode::FAILURE;
+            }
+        };
+        if let Some(xpub) = xpub {
+            return verify_xpub(&seed, &xpub);
+        }
+        if json {
+            println!("{}", seed_json(&seed, None, mask, show_fingerprint));
+            return ExitCode::SUCCESS;
+        }
+        print_seed(&seed, style, qr, show_fingerprint);
+    } else if let Some(target) = args.get_option(&["-v", "--verify"]) {
+        if json {
+            return no_json("--verify");
+        }
+        let no_validate = args.flags(&["-y", "--no-validate"]);
+        let remaining = args.remaining();
+        if remaining.is_empty() {
+            println!("error: --verify needs a seed and > 0 arguments");
+            return help(false);
+        }
+        let target = parse_seed(&target, no_validate, language);
+        let parts = read_seeds(remaining, no_validate, language);
+        return match Mnemonic::xor_all(&parts) {
+            Ok(seed) if seed.eq_up_to_padding(&target) => {
+                println!("OK");
+                ExitCode::SUCCESS
+            }
+            Ok(_) => {
+                println!("FAIL");
+                ExitCode::FAILURE
+            }
+            Err(e) => {
+                println!("error: cannot combine seeds: {e}");
+                ExitCode::FAILURE
+            }
+        };
+    } else if let Some(seed) = args.get_option(&["-a", "--address"]) {
+        if json {
+            return no_json("--address");
+        }
+        let path = args.get_option(&["--path"]);
+        let network = args.get_option(&["--network"]);
+        let seed = if seed == "-" {
+            let mut seed = String::new();
+            io::stdin()
+                .lock()
+                .read_line(&mut seed)
+                .expect("cannot read stdin");
+            seed
+        } else {
+            seed
+        };
+        let seed = parse_seed(seed.trim(), false, language);
+        return print_addresses(&seed, path, network);
     } else if args.flags(&["-u", "--unscramble"]) {
+        let fix_checksum = args.flags(&["-f", "--fix-checksum"]);
+        let threads = args.get(&["-t", "--threads"], 1usize);
+        let count = args.flags(&["--count"]);
+        let first = args.flags(&["--first"]);
         let remaining = args.remaining();
         if remaining.is_empty() {
             println!("error: --unscramble needs > 0 arguments");
             return help(false);
         }
-        let mut parts: Vec<String> = remaining
+        let (anchors, parts): (Vec<String>, Vec<String>) = remaining.into_iter().partition(|s| {
+            s.split_once('=')
+                .is_some_and(|(n, _)| n.parse::<usize>().is_ok())
+        });
+        let parts: Vec<String> = parts
             .into_iter()
-            .map(|s| expand_words(&s).expect("invalid bip39 seed words"))
+            .map(|s| expand(language, &s).unwrap_or_else(|e| exit_invalid(&s, e)))
             .collect();
+        let mut anchors: Vec<(usize, String)> = anchors
+            .iter()
+            .map(|s| {
+                let (n, word) = s.split_once('=').expect("partitioned on =");
+                let n: usize = n.parse().expect("partitioned on numbers");
+                (
+                    n,
+                    expand(language, word).unwrap_or_else(|e| exit_invalid(word, e)),
+                )
+            })
+            .collect();
+        for (position, word) in anchors.iter_mut() {
+            if *position == 0 {
+                println!("error: anchor 0={word} has no position 0, positions count from 1");
+                return help(false);
+            }
+            *position -= 1;
+        }
+        anchors.sort();
+        let word_count = parts
+            .iter()
+            .map(|p| p.split_whitespace().count())
+            .sum::<usize>()
+            + anchors.len();
+        for (i, (position, word)) in anchors.iter().enumerate() {
+            if *position >= word_count
+                || (i > 0 && anchors[i - 1].0 == *position)
+                || word.contains(' ')
+            {
+                println!(
+                    "error: anchor {}={word} must be one word at a position from 1 to {word_count}, each used once",
+                    position + 1
+                );
+                return help(false);
+            }
+        }
         let total: u128 = (1..=parts.len() as u128).product();
         eprintln!("# total permutations: {total}");
         if total > u64::MAX as u128 {
             println!("total too large, will never finish, aborting");
             return ExitCode::FAILURE;
         }
-        let mut heap = permutohedron::Heap::new(&mut parts);
-        let mut good = 0u64;
-        while let Some(words) = heap.next_permutation() {
-            let words = words.join(" ");
-            if let Ok(mnemonic) = Mnemonic::from_str(&words) {
-                if short {
-                    println!("{}", mnemonic.to_short_string());
+        let printed = AtomicBool::new(false);
+        // with --json the valid seeds are printed at the end along with the counts
+        let valid = Mutex::new(Vec::new());
+        let print = |words: &str, mnemonic: Mnemonic| {
+            if count {
+                return ControlFlow::Continue(());
+            }
+            // other threads can find one before they stop
+            if first && printed.swap(true, Ordering::Relaxed) {
+                return ControlFlow::Break(());
+            }
+            if json {
+                let seed = seed_json(&mnemonic, None, mask, show_fingerprint);
+                valid
+                    .lock()
+                    .expect("no thread panics holding it")
+                    .push(seed);
+                return if first {
+                    ControlFlow::Break(())
                 } else {
-                    println!("{words}");
+                    ControlFlow::Continue(())
+                };
+            }
+            if short || mask.is_some() || grid {
+                print_seed(&mnemonic, style, false, show_fingerprint);
+            } else {
+                match fingerprint(&mnemonic).filter(|_| show_fingerprint) {
+                    Some(fingerprint) => println!("{fingerprint}  {words}"),
+                    None => println!("{words}"),
                 }
-                good += 1;
             }
+            if first {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        };
+        let scrambled = Scrambled::new(&parts, &anchors, fix_checksum, language);
+        let (tried, good) = if threads > 1 {
+            unscramble_parallel(&scrambled, threads, print)
+        } else {
+            unscramble(&scrambled, print)
+        };
+        let bad = tried - good;
+        if json {
+            let valid = valid.into_inner().expect("no thread panics holding it");
+            println!(
+                "{{\"valid\":[{}],\"good\":{good},\"bad\":{bad},\"total\":{total},\"tried\":{tried}}}",
+                valid.join(",")
+            );
+        } else if count {
+            println!("good: {good} bad: {bad} total: {total}");
+        } else if (tried as u128) < total {
+            eprintln!("# good: {good} bad: {bad} total: {total}, stopped after {tried}");
+        } else {
+            eprintln!("# good: {good} bad: {bad} total: {total}");
+        }
+    } else if args.flags(&["-l", "--last-word"]) {
+        if json {
+            return no_json("--last-word");
+        }
+        let remaining = args.remaining();
+        if remaining.is_empty() {
+            println!("error: --last-word needs > 0 arguments");
+            return help(false);
+        }
+        let words = remaining.join(" ");
+        let words = expand(language, &words).unwrap_or_else(|e| exit_invalid(&words, e));
+        let words: Vec<&str> = words.split_whitespace().collect();
+        let last_words = match language {
+            Some(language) => Mnemonic::valid_last_words_in(language, &words),
+            None => Mnemonic::valid_last_words(&words),
+        };
+        match last_words {
+            Ok(last_words) => {
+                for last in last_words {
+                    println!("{last}");
+                }
+            }
+            Err(SeedXorError::Bip39(Error::BadWordCount(_))) => {
+                println!(
+                    "error: --last-word needs 11, 14, 17, 20 or 23 words, got {}",
+                    words.len()
+                );
+                return ExitCode::FAILURE;
+            }
+            Err(e) => exit_invalid(&words.join(" "), e),
         }
-        let bad = total - good as u128;
-        eprintln!("# good: {good} bad: {bad} total: {total}");
     } else {
-        println!("error: need one of -s/-g/-c/-u");
+        println!("error: need one of -s/-g/-c/-v/-a/-u/-l/-b");
         return help(false);
     }
     ExitCode::SUCCESS
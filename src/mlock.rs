@@ -0,0 +1,146 @@
+//! Entropy kept in memory locked with `mlock` so it is never written out to swap, and zeroed
+//! before being unlocked.
+//!
+//! Limitations:
+//! - unix only, and the amount of memory a process may lock is limited by `RLIMIT_MEMLOCK`,
+//!   [LockedMnemonic::new] fails rather than silently keeping the entropy unlocked
+//! - only the [LockedMnemonic] itself is locked, any [Mnemonic] made from it with
+//!   [LockedMnemonic::mnemonic], or parsed before being locked, is ordinary memory
+//! - a whole page is locked, so other data sharing it is locked too, but when the lock is
+//!   released so are they
+//! - hibernation writes all of memory to disk, locked or not
+
+use crate::{
+    entropy_len_from_word_count, EntropySource, Error, Language, Mnemonic, OsEntropy, SeedXorError,
+};
+use std::ptr;
+
+/// Entropy of a mnemonic held in locked memory, see the [module docs](self).
+pub struct LockedMnemonic {
+    entropy: Box<[u8; 32]>,
+    len: usize,
+    language: Language,
+    locked: bool,
+}
+
+fn errno() -> SeedXorError {
+    SeedXorError::Os(std::io::Error::last_os_error().raw_os_error().unwrap_or(0))
+}
+
+impl LockedMnemonic {
+    /// Lock a fresh zeroed buffer, errors from `mlock` are [SeedXorError::Os].
+    fn empty(language: Language, len: usize) -> Result<Self, SeedXorError> {
+        let entropy = Box::new([0u8; 32]);
+        // SAFETY: the pointer and length are those of the boxed array, which is never moved.
+        if unsafe { libc::mlock(entropy.as_ptr() as *const libc::c_void, entropy.len()) } != 0 {
+            return Err(errno());
+        }
+        Ok(LockedMnemonic {
+            entropy,
+            len,
+            language,
+            locked: true,
+        })
+    }
+
+    /// Copy the entropy of `mnemonic` into locked memory.
+    pub fn new(mnemonic: &Mnemonic) -> Result<Self, SeedXorError> {
+        let (entropy, len) = mnemonic.to_entropy_array();
+        let mut ret = Self::empty(mnemonic.language(), len)?;
+        ret.entropy[0..len].copy_from_slice(&entropy[0..len]);
+        Ok(ret)
+    }
+
+    /// [Mnemonic::generate_in] straight into locked memory.
+    pub fn generate_in(language: Language, word_count: usize) -> Result<Self, SeedXorError> {
+        let len = entropy_len_from_word_count(word_count).ok_or(Error::BadWordCount(word_count))?;
+        let mut ret = Self::empty(language, len)?;
+        OsEntropy.fill_bytes(&mut ret.entropy[0..len])?;
+        Ok(ret)
+    }
+
+    pub fn entropy(&self) -> &[u8] {
+        &self.entropy[0..self.len]
+    }
+
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// An ordinary, unlocked, [Mnemonic] of this entropy, keep it for as short a time as
+    /// possible.
+    pub fn mnemonic(&self) -> Mnemonic {
+        // We unwrap here because the length was checked when the entropy was stored.
+        bip39::Mnemonic::from_entropy_in(self.language, self.entropy())
+            .unwrap()
+            .into()
+    }
+
+    fn wipe(&mut self) {
+        for b in self.entropy.iter_mut() {
+            // SAFETY: b is a valid, aligned, exclusive reference.
+            unsafe { ptr::write_volatile(b, 0) };
+        }
+        std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+        if self.locked {
+            // SAFETY: the same pointer and length that were locked.
+            unsafe {
+                libc::munlock(
+                    self.entropy.as_ptr() as *const libc::c_void,
+                    self.entropy.len(),
+                )
+            };
+            self.locked = false;
+        }
+    }
+}
+
+impl Drop for LockedMnemonic {
+    fn drop(&mut self) {
+        self.wipe();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mlock::*;
+    use std::str::FromStr;
+
+    /// Locked memory of this process in kB, linux only.
+    fn locked_kb() -> Option<usize> {
+        let status = std::fs::read_to_string("/proc/self/status").ok()?;
+        let line = status.lines().find(|l| l.starts_with("VmLck:"))?;
+        line.split_whitespace().nth(1)?.parse().ok()
+    }
+
+    #[test]
+    fn locked_and_cleared() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let seed = Mnemonic::from_str(seed).unwrap();
+        let before = locked_kb();
+        let mut locked = match LockedMnemonic::new(&seed) {
+            Ok(locked) => locked,
+            // not allowed to lock any memory here, nothing to test
+            Err(_) => return,
+        };
+        assert_eq!(seed, locked.mnemonic());
+        assert_eq!(seed.to_entropy_array().0[0..16], *locked.entropy());
+        if let (Some(before), Some(during)) = (before, locked_kb()) {
+            assert!(during > before, "{during} > {before}");
+        }
+
+        locked.wipe();
+        assert!(locked.entropy.iter().all(|b| *b == 0));
+        assert!(!locked.locked);
+        if let (Some(before), Some(after)) = (before, locked_kb()) {
+            assert_eq!(before, after);
+        }
+
+        let generated = LockedMnemonic::generate_in(Language::English, 24).unwrap();
+        assert_eq!(24, generated.mnemonic().word_count());
+        assert_eq!(
+            Err(Error::BadWordCount(9).into()),
+            LockedMnemonic::generate_in(Language::English, 9).map(|_| ())
+        );
+    }
+}
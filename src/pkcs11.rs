@@ -0,0 +1,285 @@
+//! [EntropySource] backed by a hardware security module, through the
+//! [PKCS#11](https://docs.oasis-open.org/pkcs11/pkcs11-base/v2.40/pkcs11-base-v2.40.html)
+//! library shipped by its vendor, for when key generation must happen on certified hardware.
+//!
+//! Only the handful of functions needed to call `C_GenerateRandom` are bound, the library is
+//! loaded at runtime with `dlopen` so nothing needs to be installed to build this.
+
+use crate::{EntropySource, SeedXorError};
+use libc::{c_char, c_int, c_ulong, c_void};
+use std::{ffi::CString, num::NonZeroU32, ptr};
+
+type CkRv = c_ulong;
+type CkSlotId = c_ulong;
+type CkSessionHandle = c_ulong;
+
+const CKR_OK: CkRv = 0;
+const CKR_CRYPTOKI_ALREADY_INITIALIZED: CkRv = 0x191;
+const CKF_SERIAL_SESSION: c_ulong = 0x4;
+const CK_TRUE: u8 = 1;
+
+type InitializeFn = unsafe extern "C" fn(*mut c_void) -> CkRv;
+type FinalizeFn = unsafe extern "C" fn(*mut c_void) -> CkRv;
+type GetFunctionListFn = unsafe extern "C" fn(*mut *const FunctionList) -> CkRv;
+type GetSlotListFn = unsafe extern "C" fn(u8, *mut CkSlotId, *mut c_ulong) -> CkRv;
+type OpenSessionFn = unsafe extern "C" fn(
+    CkSlotId,
+    c_ulong,
+    *mut c_void,
+    *const c_void,
+    *mut CkSessionHandle,
+) -> CkRv;
+type CloseSessionFn = unsafe extern "C" fn(CkSessionHandle) -> CkRv;
+type GenerateRandomFn = unsafe extern "C" fn(CkSessionHandle, *mut u8, c_ulong) -> CkRv;
+
+/// The start of `CK_FUNCTION_LIST`, up to `C_GenerateRandom`, with unused entries skipped.
+#[repr(C)]
+struct FunctionList {
+    version: [u8; 2],
+    initialize: Option<InitializeFn>,
+    finalize: Option<FinalizeFn>,
+    _info: [usize; 2],
+    get_slot_list: Option<GetSlotListFn>,
+    _slots_and_tokens: [usize; 7],
+    open_session: Option<OpenSessionFn>,
+    close_session: Option<CloseSessionFn>,
+    _rest: [usize; 50],
+    generate_random: Option<GenerateRandomFn>,
+}
+
+/// PKCS#11 return values, and failure to load the library (0), are reported as
+/// [SeedXorError::Rng] with [getrandom::Error::CUSTOM_START] plus the low 30 bits of the value.
+fn failed(rv: CkRv) -> SeedXorError {
+    let code = getrandom::Error::CUSTOM_START + (rv as u32 & 0x3fff_ffff);
+    SeedXorError::Rng(NonZeroU32::new(code).expect("CUSTOM_START is not 0").into())
+}
+
+fn check(rv: CkRv) -> Result<(), SeedXorError> {
+    match rv {
+        CKR_OK => Ok(()),
+        rv => Err(failed(rv)),
+    }
+}
+
+fn missing<T>(f: Option<T>) -> Result<T, SeedXorError> {
+    f.ok_or(failed(0))
+}
+
+/// Random bytes from `C_GenerateRandom` of a PKCS#11 library, use with
+/// [Mnemonic::generate_in_with_source](crate::Mnemonic::generate_in_with_source).
+pub struct Pkcs11Source {
+    functions: *const FunctionList,
+    session: CkSessionHandle,
+    /// Whether we called `C_Initialize` so must call `C_Finalize`.
+    initialized: bool,
+    /// `dlopen` handle, null if the function list didn't come from a library we loaded.
+    library: *mut c_void,
+}
+
+impl Pkcs11Source {
+    /// Load the PKCS#11 library at `path`, for example `/usr/lib/softhsm/libsofthsm2.so`, and
+    /// open a session on `slot`, or the first slot with a token present if [None].
+    pub fn open(path: &str, slot: Option<u64>) -> Result<Self, SeedXorError> {
+        let path = CString::new(path).map_err(|_| failed(0))?;
+        // SAFETY: path is a valid C string, loading a library runs its initializers which
+        // we have to trust as the user chose it.
+        let library = unsafe { libc::dlopen(path.as_ptr(), libc::RTLD_NOW as c_int) };
+        if library.is_null() {
+            return Err(failed(0));
+        }
+        let close = |e| {
+            // SAFETY: library came from a successful dlopen and nothing from it is in use.
+            unsafe { libc::dlclose(library) };
+            e
+        };
+        let name = b"C_GetFunctionList\0";
+        // SAFETY: library is a valid handle and name is nul terminated.
+        let get_function_list = unsafe { libc::dlsym(library, name.as_ptr() as *const c_char) };
+        if get_function_list.is_null() {
+            return Err(close(failed(0)));
+        }
+        // SAFETY: C_GetFunctionList has this signature in every PKCS#11 library.
+        let get_function_list: GetFunctionListFn =
+            unsafe { std::mem::transmute(get_function_list) };
+        let mut functions = ptr::null();
+        // SAFETY: functions is a valid place to write the list pointer to.
+        check(unsafe { get_function_list(&mut functions) }).map_err(close)?;
+        if functions.is_null() {
+            return Err(close(failed(0)));
+        }
+        // SAFETY: the library returned a valid function list that lives as long as it is loaded.
+        let mut source = unsafe { Self::from_function_list(functions, slot) }.map_err(close)?;
+        source.library = library;
+        Ok(source)
+    }
+
+    /// # Safety
+    /// `functions` must point to a valid `CK_FUNCTION_LIST` that outlives the returned source.
+    unsafe fn from_function_list(
+        functions: *const FunctionList,
+        slot: Option<u64>,
+    ) -> Result<Self, SeedXorError> {
+        let list = &*functions;
+        let initialized = match missing(list.initialize)?(ptr::null_mut()) {
+            CKR_CRYPTOKI_ALREADY_INITIALIZED => false,
+            rv => check(rv).map(|_| true)?,
+        };
+        let mut source = Pkcs11Source {
+            functions,
+            session: 0,
+            initialized,
+            library: ptr::null_mut(),
+        };
+        let slot = match slot {
+            Some(slot) => slot as CkSlotId,
+            None => {
+                let get_slot_list = missing(list.get_slot_list)?;
+                let mut count: c_ulong = 0;
+                check(get_slot_list(CK_TRUE, ptr::null_mut(), &mut count))?;
+                let mut slots = vec![0 as CkSlotId; count as usize];
+                check(get_slot_list(CK_TRUE, slots.as_mut_ptr(), &mut count))?;
+                *slots
+                    .get(..count as usize)
+                    .and_then(|s| s.first())
+                    .ok_or(failed(0))?
+            }
+        };
+        let mut session = 0;
+        check(missing(list.open_session)?(
+            slot,
+            CKF_SERIAL_SESSION,
+            ptr::null_mut(),
+            ptr::null(),
+            &mut session,
+        ))?;
+        source.session = session;
+        Ok(source)
+    }
+}
+
+impl EntropySource for Pkcs11Source {
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), SeedXorError> {
+        // SAFETY: functions is valid for the life of self, and dest is writable for its length.
+        unsafe {
+            let generate_random = missing((*self.functions).generate_random)?;
+            check(generate_random(
+                self.session,
+                dest.as_mut_ptr(),
+                dest.len() as c_ulong,
+            ))
+        }
+    }
+}
+
+impl Drop for Pkcs11Source {
+    fn drop(&mut self) {
+        // SAFETY: functions is valid until the library is closed, which happens last.
+        unsafe {
+            let list = &*self.functions;
+            if self.session != 0 {
+                if let Some(close_session) = list.close_session {
+                    close_session(self.session);
+                }
+            }
+            if self.initialized {
+                if let Some(finalize) = list.finalize {
+                    finalize(ptr::null_mut());
+                }
+            }
+            if !self.library.is_null() {
+                libc::dlclose(self.library);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pkcs11::*;
+    use crate::{Language, Mnemonic};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    unsafe extern "C" fn initialize(_: *mut c_void) -> CkRv {
+        CALLS.fetch_add(1, Ordering::Relaxed);
+        CKR_OK
+    }
+
+    unsafe extern "C" fn finalize(_: *mut c_void) -> CkRv {
+        CALLS.fetch_add(1, Ordering::Relaxed);
+        CKR_OK
+    }
+
+    unsafe extern "C" fn get_slot_list(_: u8, slots: *mut CkSlotId, count: *mut c_ulong) -> CkRv {
+        if !slots.is_null() {
+            *slots = 7;
+        }
+        *count = 1;
+        CKR_OK
+    }
+
+    unsafe extern "C" fn open_session(
+        slot: CkSlotId,
+        _: c_ulong,
+        _: *mut c_void,
+        _: *const c_void,
+        session: *mut CkSessionHandle,
+    ) -> CkRv {
+        *session = slot * 100;
+        CKR_OK
+    }
+
+    unsafe extern "C" fn close_session(session: CkSessionHandle) -> CkRv {
+        assert_eq!(700, session);
+        CALLS.fetch_add(1, Ordering::Relaxed);
+        CKR_OK
+    }
+
+    unsafe extern "C" fn generate_random(
+        session: CkSessionHandle,
+        out: *mut u8,
+        len: c_ulong,
+    ) -> CkRv {
+        if session != 700 {
+            return 0xB3; // CKR_SESSION_HANDLE_INVALID
+        }
+        for i in 0..len as usize {
+            *out.add(i) = i as u8;
+        }
+        CKR_OK
+    }
+
+    #[test]
+    fn mocked_pkcs11_source() {
+        let mock = FunctionList {
+            version: [2, 40],
+            initialize: Some(initialize),
+            finalize: Some(finalize),
+            _info: [0; 2],
+            get_slot_list: Some(get_slot_list),
+            _slots_and_tokens: [0; 7],
+            open_session: Some(open_session),
+            close_session: Some(close_session),
+            _rest: [0; 50],
+            generate_random: Some(generate_random),
+        };
+        {
+            let mut source = unsafe { Pkcs11Source::from_function_list(&mock, None) }.unwrap();
+            let mnemonic =
+                Mnemonic::generate_in_with_source(Language::English, 24, &mut source).unwrap();
+            assert_eq!(
+                Mnemonic::from_entropy(&(0..32).collect::<Vec<u8>>()).unwrap(),
+                mnemonic
+            );
+        }
+        // initialize, close_session and finalize
+        assert_eq!(3, CALLS.load(Ordering::Relaxed));
+
+        let mut source = unsafe { Pkcs11Source::from_function_list(&mock, Some(3)) }.unwrap();
+        assert_eq!(Err(failed(0xB3)), source.fill_bytes(&mut [0; 16]));
+        source.session = 0;
+
+        assert!(Pkcs11Source::open("/nonexistent/libpkcs11.so", None).is_err());
+    }
+}
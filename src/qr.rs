@@ -0,0 +1,554 @@
+//! Just enough of a [QR code](https://www.iso.org/standard/62021.html) encoder to show a seed
+//! on a terminal for an air-gapped signer to scan, no dependencies needed.
+//!
+//! Only byte mode at error correction level M is written, versions 1 to 13, which holds up to
+//! [MAX_BYTES], more than any 24 word English mnemonic.
+
+use crate::SeedXorError;
+use alloc::{string::String, vec, vec::Vec};
+
+/// Most bytes [QrCode::encode] can fit, in a version 13 code.
+pub const MAX_BYTES: usize = 331;
+/// Light modules around the code, scanners need at least 4.
+const QUIET_ZONE: usize = 4;
+
+/// Error correction level M block structure of each version: error correction codewords per
+/// block, number of short blocks, data codewords per short block and number of long blocks,
+/// which have one data codeword more.
+const BLOCKS: [(usize, usize, usize, usize); 13] = [
+    (10, 1, 16, 0),
+    (16, 1, 28, 0),
+    (26, 1, 44, 0),
+    (18, 2, 32, 0),
+    (24, 2, 43, 0),
+    (16, 4, 27, 0),
+    (18, 4, 31, 0),
+    (22, 2, 38, 2),
+    (22, 3, 36, 2),
+    (26, 4, 43, 1),
+    (30, 1, 50, 4),
+    (22, 6, 36, 2),
+    (22, 8, 37, 1),
+];
+
+/// Multiplication modulo the QR polynomial x^8 + x^4 + x^3 + x^2 + 1, which is not the AES one
+/// of [crate::shamir].
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut ret = 0;
+    while b != 0 {
+        if b & 1 == 1 {
+            ret ^= a;
+        }
+        a = (a << 1) ^ (0x1d & (a >> 7).wrapping_neg());
+        b >>= 1;
+    }
+    ret
+}
+
+/// Reed-Solomon generator polynomial of `degree`, highest power first without its leading 1.
+fn rs_divisor(degree: usize) -> Vec<u8> {
+    let mut ret = vec![0u8; degree];
+    ret[degree - 1] = 1;
+    let mut root = 1u8;
+    for _ in 0..degree {
+        for j in 0..degree {
+            ret[j] = gf_mul(ret[j], root);
+            if j + 1 < degree {
+                ret[j] ^= ret[j + 1];
+            }
+        }
+        root = gf_mul(root, 0x02);
+    }
+    ret
+}
+
+/// Error correction codewords of `data`.
+fn rs_remainder(data: &[u8], divisor: &[u8]) -> Vec<u8> {
+    let mut ret = vec![0u8; divisor.len()];
+    for b in data {
+        let factor = b ^ ret.remove(0);
+        ret.push(0);
+        ret.iter_mut()
+            .zip(divisor)
+            .for_each(|(r, d)| *r ^= gf_mul(*d, factor));
+    }
+    ret
+}
+
+fn data_codewords(version: usize) -> usize {
+    let (_, short, short_len, long) = BLOCKS[version - 1];
+    short * short_len + long * (short_len + 1)
+}
+
+/// Bytes that fit in `version`, after the 4 bit mode and 8 or 16 bit length.
+fn capacity(version: usize) -> usize {
+    data_codewords(version) - if version < 10 { 2 } else { 3 }
+}
+
+/// Centers of the alignment patterns along either axis.
+fn alignment_positions(version: usize) -> Vec<usize> {
+    if version == 1 {
+        return Vec::new();
+    }
+    let size = version * 4 + 17;
+    let count = version / 7 + 2;
+    let step = (version * 4 + count * 2 + 1) / (count * 2 - 2) * 2;
+    let mut ret: Vec<usize> = (0..count - 1).map(|i| size - 7 - i * step).collect();
+    ret.push(6);
+    ret.reverse();
+    ret
+}
+
+/// The 15 format bits of level M with `mask`, BCH protected and masked.
+fn format_bits(mask: u8) -> u32 {
+    // level M is 00
+    let data = mask as u32;
+    let rem = (0..10).fold(data, |rem, _| (rem << 1) ^ ((rem >> 9) * 0x537));
+    ((data << 10) | rem) ^ 0x5412
+}
+
+/// The 18 version bits, only drawn from version 7 up.
+fn version_bits(version: usize) -> u32 {
+    let version = version as u32;
+    let rem = (0..12).fold(version, |rem, _| (rem << 1) ^ ((rem >> 11) * 0x1f25));
+    (version << 12) | rem
+}
+
+fn mask_bit(mask: u8, x: usize, y: usize) -> bool {
+    match mask {
+        0 => (x + y).is_multiple_of(2),
+        1 => y.is_multiple_of(2),
+        2 => x.is_multiple_of(3),
+        3 => (x + y).is_multiple_of(3),
+        4 => (x / 3 + y / 2).is_multiple_of(2),
+        5 => x * y % 2 + x * y % 3 == 0,
+        6 => (x * y % 2 + x * y % 3).is_multiple_of(2),
+        _ => ((x + y) % 2 + x * y % 3).is_multiple_of(2),
+    }
+}
+
+/// A QR code being drawn, with which modules are patterns rather than data.
+struct Grid {
+    size: usize,
+    modules: Vec<bool>,
+    function: Vec<bool>,
+}
+
+impl Grid {
+    fn set_function(&mut self, x: usize, y: usize, dark: bool) {
+        self.modules[y * self.size + x] = dark;
+        self.function[y * self.size + x] = true;
+    }
+
+    /// Finder pattern centered on `x`, `y`, with its light separator.
+    fn finder(&mut self, x: usize, y: usize) {
+        for dy in -4isize..=4 {
+            for dx in -4isize..=4 {
+                let (px, py) = (x as isize + dx, y as isize + dy);
+                if (0..self.size as isize).contains(&px) && (0..self.size as isize).contains(&py) {
+                    let dist = dx.abs().max(dy.abs());
+                    self.set_function(px as usize, py as usize, dist != 2 && dist != 4);
+                }
+            }
+        }
+    }
+
+    fn alignment(&mut self, x: usize, y: usize) {
+        for dy in 0..5 {
+            for dx in 0..5 {
+                let dist = (dx as isize - 2).abs().max((dy as isize - 2).abs());
+                self.set_function(x + dx - 2, y + dy - 2, dist != 1);
+            }
+        }
+    }
+
+    fn format(&mut self, mask: u8) {
+        let bits = format_bits(mask);
+        let bit = |i: usize| (bits >> i) & 1 == 1;
+        let size = self.size;
+        for i in 0..6 {
+            self.set_function(8, i, bit(i));
+        }
+        self.set_function(8, 7, bit(6));
+        self.set_function(8, 8, bit(7));
+        self.set_function(7, 8, bit(8));
+        for i in 9..15 {
+            self.set_function(14 - i, 8, bit(i));
+        }
+        for i in 0..8 {
+            self.set_function(size - 1 - i, 8, bit(i));
+        }
+        for i in 8..15 {
+            self.set_function(8, size - 15 + i, bit(i));
+        }
+        self.set_function(8, size - 8, true);
+    }
+
+    fn version(&mut self, version: usize) {
+        let bits = version_bits(version);
+        for i in 0..18 {
+            let dark = (bits >> i) & 1 == 1;
+            let (a, b) = (self.size - 11 + i % 3, i / 3);
+            self.set_function(a, b, dark);
+            self.set_function(b, a, dark);
+        }
+    }
+
+    /// Positions of the data modules in the order codeword bits go, two module wide columns
+    /// zigzagging up and down from the bottom right, skipping the vertical timing pattern.
+    fn data_positions(&self) -> Vec<(usize, usize)> {
+        let size = self.size;
+        let mut ret = Vec::new();
+        let mut right = size - 1;
+        loop {
+            if right == 6 {
+                right = 5;
+            }
+            let upward = (right + 1) & 2 == 0;
+            for vert in 0..size {
+                let y = if upward { size - 1 - vert } else { vert };
+                for x in [right, right - 1] {
+                    if !self.function[y * size + x] {
+                        ret.push((x, y));
+                    }
+                }
+            }
+            if right < 2 {
+                break ret;
+            }
+            right -= 2;
+        }
+    }
+
+    fn apply_mask(&mut self, mask: u8) {
+        for y in 0..self.size {
+            for x in 0..self.size {
+                if !self.function[y * self.size + x] && mask_bit(mask, x, y) {
+                    self.modules[y * self.size + x] ^= true;
+                }
+            }
+        }
+    }
+
+    /// The standard penalty of how hard the code is to scan, the mask with the lowest wins.
+    fn penalty(&self) -> usize {
+        let size = self.size;
+        let dark = |x: usize, y: usize| self.modules[y * size + x];
+        let mut ret = 0;
+        // finder like 1:1:3:1:1 runs with 4 light modules on one side, beyond the edge is light
+        const FINDER: [bool; 11] = [
+            true, false, true, true, true, false, true, false, false, false, false,
+        ];
+        for horizontal in [true, false] {
+            for a in 0..size {
+                let line: Vec<bool> = (0..size)
+                    .map(|b| if horizontal { dark(b, a) } else { dark(a, b) })
+                    .collect();
+                let mut run = 1;
+                for b in 1..=size {
+                    if b < size && line[b] == line[b - 1] {
+                        run += 1;
+                        continue;
+                    }
+                    if run >= 5 {
+                        ret += run - 2;
+                    }
+                    run = 1;
+                }
+                let at = |i: isize| i >= 0 && (i as usize) < size && line[i as usize];
+                for start in -4isize..size as isize {
+                    let forward = (0..11).all(|i| at(start + i) == FINDER[i as usize]);
+                    let backward = (0..11).all(|i| at(start + i) == FINDER[10 - i as usize]);
+                    ret += 40 * (forward as usize + backward as usize);
+                }
+            }
+        }
+        for y in 0..size - 1 {
+            for x in 0..size - 1 {
+                let c = dark(x, y);
+                if c == dark(x + 1, y) && c == dark(x, y + 1) && c == dark(x + 1, y + 1) {
+                    ret += 3;
+                }
+            }
+        }
+        let total = size * size;
+        let dark = self.modules.iter().filter(|d| **d).count();
+        let k = (dark * 20).abs_diff(total * 10).div_ceil(total);
+        ret + k.saturating_sub(1) * 10
+    }
+}
+
+/// A QR code, see the [module docs](crate::qr).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QrCode {
+    size: usize,
+    modules: Vec<bool>,
+}
+
+impl QrCode {
+    /// Encode `data` in the smallest version that fits, more than [MAX_BYTES] is a
+    /// [SeedXorError::LengthMismatch].
+    pub fn encode(data: &[u8]) -> Result<QrCode, SeedXorError> {
+        let version = (1..=BLOCKS.len())
+            .find(|v| capacity(*v) >= data.len())
+            .ok_or(SeedXorError::LengthMismatch {
+                expected: MAX_BYTES,
+                got: data.len(),
+            })?;
+        let codewords = codewords(version, data);
+
+        let mut grid = template(version);
+        let size = grid.size;
+        // modules left over after the last codeword are remainder bits, which stay light
+        for (i, (x, y)) in grid.data_positions().into_iter().enumerate() {
+            if i < codewords.len() * 8 {
+                grid.modules[y * size + x] = (codewords[i / 8] >> (7 - i % 8)) & 1 == 1;
+            }
+        }
+
+        let mask = (0..8)
+            .min_by_key(|mask| {
+                grid.apply_mask(*mask);
+                grid.format(*mask);
+                let penalty = grid.penalty();
+                grid.apply_mask(*mask);
+                penalty
+            })
+            .expect("8 masks");
+        grid.apply_mask(mask);
+        grid.format(mask);
+        Ok(QrCode {
+            size,
+            modules: grid.modules,
+        })
+    }
+
+    /// Modules per side, without the quiet zone.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Whether the module at column `x` and row `y` is dark, `0, 0` is top left.
+    pub fn is_dark(&self, x: usize, y: usize) -> bool {
+        self.modules[y * self.size + x]
+    }
+
+    /// The code, with its quiet zone, as lines of unicode half blocks, two rows of modules per
+    /// line.
+    ///
+    /// Light modules are the blocks, so it scans on a terminal with light text on a dark
+    /// background.
+    pub fn to_terminal_string(&self) -> String {
+        let full = self.size + 2 * QUIET_ZONE;
+        let light = |x: usize, y: usize| {
+            // below the bottom quiet zone is nothing, which shows as dark
+            y < full
+                && (x < QUIET_ZONE
+                    || y < QUIET_ZONE
+                    || x >= self.size + QUIET_ZONE
+                    || y >= self.size + QUIET_ZONE
+                    || !self.is_dark(x - QUIET_ZONE, y - QUIET_ZONE))
+        };
+        let mut ret = String::with_capacity((full + 1) * full.div_ceil(2) * 3);
+        for y in (0..full).step_by(2) {
+            for x in 0..full {
+                ret.push(match (light(x, y), light(x, y + 1)) {
+                    (true, true) => '█',
+                    (true, false) => '▀',
+                    (false, true) => '▄',
+                    (false, false) => ' ',
+                });
+            }
+            ret.push('\n');
+        }
+        ret
+    }
+}
+
+/// An empty `version` code with all its patterns drawn, and the format modules reserved.
+fn template(version: usize) -> Grid {
+    let size = version * 4 + 17;
+    let mut grid = Grid {
+        size,
+        modules: vec![false; size * size],
+        function: vec![false; size * size],
+    };
+    for i in 0..size {
+        grid.set_function(6, i, i % 2 == 0);
+        grid.set_function(i, 6, i % 2 == 0);
+    }
+    grid.finder(3, 3);
+    grid.finder(size - 4, 3);
+    grid.finder(3, size - 4);
+    let positions = alignment_positions(version);
+    let last = positions.len().saturating_sub(1);
+    for (i, x) in positions.iter().enumerate() {
+        for (j, y) in positions.iter().enumerate() {
+            // the three corners with finder patterns have none
+            if ![(0, 0), (0, last), (last, 0)].contains(&(i, j)) {
+                grid.alignment(*x, *y);
+            }
+        }
+    }
+    // drawn for real along with the mask
+    grid.format(0);
+    if version >= 7 {
+        grid.version(version);
+    }
+    grid
+}
+
+/// Append the low `len` bits of `value`, most significant first.
+fn push_bits(bits: &mut Vec<bool>, value: usize, len: usize) {
+    bits.extend((0..len).rev().map(|i| (value >> i) & 1 == 1));
+}
+
+/// Data and error correction codewords of `data` in `version`, interleaved across blocks.
+fn codewords(version: usize, data: &[u8]) -> Vec<u8> {
+    let mut bits: Vec<bool> = Vec::with_capacity(data_codewords(version) * 8);
+    push_bits(&mut bits, 0b0100, 4);
+    push_bits(&mut bits, data.len(), if version < 10 { 8 } else { 16 });
+    for b in data {
+        push_bits(&mut bits, *b as usize, 8);
+    }
+    let capacity_bits = data_codewords(version) * 8;
+    let terminator = 4.min(capacity_bits - bits.len());
+    push_bits(&mut bits, 0, terminator);
+    let padding = (8 - bits.len() % 8) % 8;
+    push_bits(&mut bits, 0, padding);
+    for pad in [0xec, 0x11].into_iter().cycle() {
+        if bits.len() >= capacity_bits {
+            break;
+        }
+        push_bits(&mut bits, pad, 8);
+    }
+    let bytes: Vec<u8> = bits
+        .chunks(8)
+        .map(|byte| byte.iter().fold(0, |acc, b| (acc << 1) | *b as u8))
+        .collect();
+
+    let (ec, short, short_len, long) = BLOCKS[version - 1];
+    let divisor = rs_divisor(ec);
+    let mut blocks = Vec::with_capacity(short + long);
+    let mut rest = &bytes[..];
+    for i in 0..short + long {
+        let (block, tail) = rest.split_at(short_len + (i >= short) as usize);
+        blocks.push((block, rs_remainder(block, &divisor)));
+        rest = tail;
+    }
+    let mut ret = Vec::with_capacity(bytes.len() + blocks.len() * ec);
+    for i in 0..=short_len {
+        ret.extend(blocks.iter().filter_map(|(block, _)| block.get(i)));
+    }
+    for i in 0..ec {
+        ret.extend(blocks.iter().map(|(_, ecc)| ecc[i]));
+    }
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::qr::*;
+
+    #[test]
+    fn reed_solomon() {
+        // HELLO WORLD in version 1-M, from https://www.thonky.com/qr-code-tutorial/
+        let data = [
+            32, 91, 11, 120, 209, 114, 220, 77, 67, 64, 236, 17, 236, 17, 236, 17,
+        ];
+        assert_eq!(
+            vec![196, 35, 39, 119, 235, 215, 231, 226, 93, 23],
+            rs_remainder(&data, &rs_divisor(10))
+        );
+    }
+
+    #[test]
+    fn format_and_version_bits() {
+        assert_eq!(0b101010000010010, format_bits(0));
+        assert_eq!(0b101000100100101, format_bits(1));
+        assert_eq!(0x07c94, version_bits(7));
+        assert_eq!(0x085bc, version_bits(8));
+        assert_eq!(vec![6, 22, 38], alignment_positions(7));
+        assert_eq!(vec![6, 34, 62], alignment_positions(13));
+    }
+
+    #[test]
+    fn versions() {
+        assert_eq!(21, QrCode::encode(&[b'a'; 14]).unwrap().size());
+        assert_eq!(25, QrCode::encode(&[b'a'; 15]).unwrap().size());
+        assert_eq!(57, QrCode::encode(&[b'a'; 213]).unwrap().size());
+        assert_eq!(61, QrCode::encode(&[b'a'; 214]).unwrap().size());
+        assert_eq!(69, QrCode::encode(&[b'a'; MAX_BYTES]).unwrap().size());
+        assert_eq!(
+            Err(SeedXorError::LengthMismatch {
+                expected: MAX_BYTES,
+                got: MAX_BYTES + 1
+            }),
+            QrCode::encode(&[b'a'; MAX_BYTES + 1])
+        );
+        for version in 1..=BLOCKS.len() {
+            let (ec, short, short_len, long) = BLOCKS[version - 1];
+            let size = version * 4 + 17;
+            assert_eq!(
+                codewords(version, &[]).len(),
+                ec * (short + long) + short * short_len + long * (short_len + 1)
+            );
+            // every data module gets a codeword bit, bar the 0 or 7 remainder bits
+            let grid = template(version);
+            let remainder = grid.data_positions().len() - codewords(version, &[]).len() * 8;
+            assert_eq!(if (2..7).contains(&version) { 7 } else { 0 }, remainder);
+            let qr = QrCode::encode(&vec![b'a'; capacity(version)]).unwrap();
+            assert_eq!(size, qr.size());
+        }
+    }
+
+    #[test]
+    fn modules_read_back() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let qr = QrCode::encode(seed.as_bytes()).unwrap();
+        let size = qr.size();
+        // finder patterns and timing
+        for (x, y) in [(0, 0), (size - 7, 0), (0, size - 7)] {
+            assert!(qr.is_dark(x, y) && qr.is_dark(x + 6, y + 6) && qr.is_dark(x + 3, y + 3));
+            assert!(!qr.is_dark(x + 1, y + 1) && !qr.is_dark(x + 5, y + 5));
+        }
+        assert!((8..size - 8).all(|i| qr.is_dark(i, 6) == (i % 2 == 0)));
+
+        // read the format bits and data back out like a scanner would
+        let format = (0..15).fold(0u32, |acc, i| {
+            let (x, y) = if i < 8 {
+                (size - 1 - i, 8)
+            } else {
+                (8, size - 15 + i)
+            };
+            acc | (qr.is_dark(x, y) as u32) << i
+        });
+        let mask = (0..8).find(|m| format_bits(*m) == format).unwrap();
+        let version = (size - 17) / 4;
+        let mut grid = template(version);
+        grid.modules = qr.modules.clone();
+        grid.apply_mask(mask);
+        let expected = codewords(version, seed.as_bytes());
+        let bits: Vec<bool> = grid
+            .data_positions()
+            .iter()
+            .take(expected.len() * 8)
+            .map(|(x, y)| grid.modules[y * size + x])
+            .collect();
+        let read: Vec<u8> = bits
+            .chunks(8)
+            .map(|byte| byte.iter().fold(0, |acc, b| (acc << 1) | *b as u8))
+            .collect();
+        assert_eq!(expected, read);
+    }
+
+    #[test]
+    fn terminal_string() {
+        let qr = QrCode::encode(b"seedxor").unwrap();
+        let s = qr.to_terminal_string();
+        let lines: Vec<&str> = s.lines().collect();
+        assert_eq!(15, lines.len());
+        assert!(lines.iter().all(|l| l.chars().count() == 21 + 8));
+        assert!(lines[0].chars().all(|c| c == '█'));
+    }
+}
@@ -0,0 +1,293 @@
+//! Just enough secp256k1 to turn a private key into a compressed public key, for BIP32
+//! derivation of public keys and fingerprints.
+//!
+//! This is plain textbook arithmetic on 4x64 bit limbs and is *not* constant time, it is only
+//! meant for checking which wallet a seed belongs to on a machine you trust, never for signing.
+
+/// 256 bit integer, least significant limb first.
+type U256 = [u64; 4];
+
+const P: U256 = [
+    0xFFFFFFFEFFFFFC2F,
+    0xFFFFFFFFFFFFFFFF,
+    0xFFFFFFFFFFFFFFFF,
+    0xFFFFFFFFFFFFFFFF,
+];
+/// 2^256 - P
+const P_COMPLEMENT: u64 = 0x1000003D1;
+pub(crate) const N: U256 = [
+    0xBFD25E8CD0364141,
+    0xBAAEDCE6AF48A03B,
+    0xFFFFFFFFFFFFFFFE,
+    0xFFFFFFFFFFFFFFFF,
+];
+const GX: U256 = [
+    0x59F2815B16F81798,
+    0x029BFCDB2DCE28D9,
+    0x55A06295CE870B07,
+    0x79BE667EF9DCBBAC,
+];
+const GY: U256 = [
+    0x9C47D08FFB10D4B8,
+    0xFD17B448A6855419,
+    0x5DA4FBFC0E1108A8,
+    0x483ADA7726A3C465,
+];
+const ZERO: U256 = [0; 4];
+const ONE: U256 = [1, 0, 0, 0];
+
+pub(crate) fn from_be_bytes(bytes: &[u8; 32]) -> U256 {
+    let mut ret = ZERO;
+    for (i, limb) in ret.iter_mut().enumerate() {
+        let start = 32 - 8 * (i + 1);
+        *limb = u64::from_be_bytes(bytes[start..start + 8].try_into().expect("8 bytes"));
+    }
+    ret
+}
+
+pub(crate) fn to_be_bytes(a: &U256) -> [u8; 32] {
+    let mut ret = [0u8; 32];
+    for (i, limb) in a.iter().enumerate() {
+        let start = 32 - 8 * (i + 1);
+        ret[start..start + 8].copy_from_slice(&limb.to_be_bytes());
+    }
+    ret
+}
+
+fn add(a: &U256, b: &U256) -> (U256, bool) {
+    let mut ret = ZERO;
+    let mut carry = false;
+    for i in 0..4 {
+        let (sum, c1) = a[i].overflowing_add(b[i]);
+        let (sum, c2) = sum.overflowing_add(carry as u64);
+        ret[i] = sum;
+        carry = c1 || c2;
+    }
+    (ret, carry)
+}
+
+fn sub(a: &U256, b: &U256) -> (U256, bool) {
+    let mut ret = ZERO;
+    let mut borrow = false;
+    for i in 0..4 {
+        let (diff, b1) = a[i].overflowing_sub(b[i]);
+        let (diff, b2) = diff.overflowing_sub(borrow as u64);
+        ret[i] = diff;
+        borrow = b1 || b2;
+    }
+    (ret, borrow)
+}
+
+/// Whether `a < b`.
+pub(crate) fn lt(a: &U256, b: &U256) -> bool {
+    sub(a, b).1
+}
+
+/// `a + b mod m` for `a, b < m`.
+pub(crate) fn add_mod(a: &U256, b: &U256, m: &U256) -> U256 {
+    let (sum, carry) = add(a, b);
+    if carry || !lt(&sum, m) {
+        sub(&sum, m).0
+    } else {
+        sum
+    }
+}
+
+fn sub_mod(a: &U256, b: &U256) -> U256 {
+    let (diff, borrow) = sub(a, b);
+    if borrow {
+        add(&diff, &P).0
+    } else {
+        diff
+    }
+}
+
+fn mul_mod(a: &U256, b: &U256) -> U256 {
+    let mut wide = [0u64; 8];
+    for i in 0..4 {
+        let mut carry = 0u128;
+        for j in 0..4 {
+            let t = wide[i + j] as u128 + a[i] as u128 * b[j] as u128 + carry;
+            wide[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        wide[i + 4] = carry as u64;
+    }
+    // hi * 2^256 + lo = hi * P_COMPLEMENT + lo mod P, done twice to get back under 2^256
+    let mut folded = [0u64; 5];
+    let mut carry = 0u128;
+    for i in 0..4 {
+        let t = wide[i] as u128 + wide[i + 4] as u128 * P_COMPLEMENT as u128 + carry;
+        folded[i] = t as u64;
+        carry = t >> 64;
+    }
+    folded[4] = carry as u64;
+    let mut ret = ZERO;
+    let mut carry = folded[4] as u128 * P_COMPLEMENT as u128;
+    for i in 0..4 {
+        let t = folded[i] as u128 + carry;
+        ret[i] = t as u64;
+        carry = t >> 64;
+    }
+    if carry != 0 {
+        // wrapped past 2^256 which is P_COMPLEMENT mod P, and ret is tiny so this won't again
+        ret = add(&ret, &[P_COMPLEMENT, 0, 0, 0]).0;
+    }
+    if !lt(&ret, &P) {
+        ret = sub(&ret, &P).0;
+    }
+    ret
+}
+
+fn inv_mod(a: &U256) -> U256 {
+    // Fermat, a^(P - 2)
+    let exp = sub(&P, &[2, 0, 0, 0]).0;
+    let mut ret = ONE;
+    for bit in (0..256).rev() {
+        ret = mul_mod(&ret, &ret);
+        if (exp[bit / 64] >> (bit % 64)) & 1 == 1 {
+            ret = mul_mod(&ret, a);
+        }
+    }
+    ret
+}
+
+/// Jacobian coordinates, z = 0 is the point at infinity.
+#[derive(Clone, Copy)]
+struct Point {
+    x: U256,
+    y: U256,
+    z: U256,
+}
+
+const INFINITY: Point = Point {
+    x: ONE,
+    y: ONE,
+    z: ZERO,
+};
+
+impl Point {
+    fn double(&self) -> Point {
+        if self.z == ZERO || self.y == ZERO {
+            return INFINITY;
+        }
+        let a = mul_mod(&self.x, &self.x);
+        let b = mul_mod(&self.y, &self.y);
+        let c = mul_mod(&b, &b);
+        let x_b = add_mod(&self.x, &b, &P);
+        let d = sub_mod(&sub_mod(&mul_mod(&x_b, &x_b), &a), &c);
+        let d = add_mod(&d, &d, &P);
+        let e = add_mod(&add_mod(&a, &a, &P), &a, &P);
+        let f = mul_mod(&e, &e);
+        let x = sub_mod(&f, &add_mod(&d, &d, &P));
+        let c8 = add_mod(&c, &c, &P);
+        let c8 = add_mod(&c8, &c8, &P);
+        let c8 = add_mod(&c8, &c8, &P);
+        let y = sub_mod(&mul_mod(&e, &sub_mod(&d, &x)), &c8);
+        let z = mul_mod(&self.y, &self.z);
+        let z = add_mod(&z, &z, &P);
+        Point { x, y, z }
+    }
+
+    fn add(&self, other: &Point) -> Point {
+        if self.z == ZERO {
+            return *other;
+        }
+        if other.z == ZERO {
+            return *self;
+        }
+        let z1z1 = mul_mod(&self.z, &self.z);
+        let z2z2 = mul_mod(&other.z, &other.z);
+        let u1 = mul_mod(&self.x, &z2z2);
+        let u2 = mul_mod(&other.x, &z1z1);
+        let s1 = mul_mod(&mul_mod(&self.y, &other.z), &z2z2);
+        let s2 = mul_mod(&mul_mod(&other.y, &self.z), &z1z1);
+        let h = sub_mod(&u2, &u1);
+        let r = sub_mod(&s2, &s1);
+        if h == ZERO {
+            return if r == ZERO { self.double() } else { INFINITY };
+        }
+        let hh = mul_mod(&h, &h);
+        let hhh = mul_mod(&h, &hh);
+        let v = mul_mod(&u1, &hh);
+        let x = sub_mod(&sub_mod(&mul_mod(&r, &r), &hhh), &add_mod(&v, &v, &P));
+        let y = sub_mod(&mul_mod(&r, &sub_mod(&v, &x)), &mul_mod(&s1, &hhh));
+        let z = mul_mod(&mul_mod(&self.z, &other.z), &h);
+        Point { x, y, z }
+    }
+}
+
+/// The 33 byte compressed public key of the private key `secret`, which must be in `1..N`.
+pub(crate) fn public_key(secret: &[u8; 32]) -> [u8; 33] {
+    let k = from_be_bytes(secret);
+    let g = Point {
+        x: GX,
+        y: GY,
+        z: ONE,
+    };
+    let mut point = INFINITY;
+    for bit in (0..256).rev() {
+        point = point.double();
+        if (k[bit / 64] >> (bit % 64)) & 1 == 1 {
+            point = point.add(&g);
+        }
+    }
+    let z_inv = inv_mod(&point.z);
+    let z_inv2 = mul_mod(&z_inv, &z_inv);
+    let x = mul_mod(&point.x, &z_inv2);
+    let y = mul_mod(&point.y, &mul_mod(&z_inv2, &z_inv));
+
+    let mut ret = [0u8; 33];
+    ret[0] = 2 + (y[0] & 1) as u8;
+    ret[1..].copy_from_slice(&to_be_bytes(&x));
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::secp256k1::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn public_keys() {
+        let mut secret = [0u8; 32];
+        secret[31] = 1;
+        assert_eq!(
+            "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            hex(&public_key(&secret))
+        );
+        secret[31] = 2;
+        assert_eq!(
+            "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+            hex(&public_key(&secret))
+        );
+        secret[31] = 3;
+        assert_eq!(
+            "02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+            hex(&public_key(&secret))
+        );
+        // N - 1 is -G, with the opposite y parity
+        let mut secret = to_be_bytes(&sub(&N, &ONE).0);
+        assert_eq!(
+            "0379be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            hex(&public_key(&secret))
+        );
+        secret = [0xff; 32];
+        secret[0] = 0x7f;
+        assert_eq!(33, public_key(&secret).len());
+    }
+
+    #[test]
+    fn field_arithmetic() {
+        let p_minus_1 = sub(&P, &ONE).0;
+        // (-1)^2 = 1
+        assert_eq!(ONE, mul_mod(&p_minus_1, &p_minus_1));
+        assert_eq!(ZERO, add_mod(&p_minus_1, &ONE, &P));
+        let a = [0x1234, 0x5678, 0x9abc, 0x7def];
+        assert_eq!(ONE, mul_mod(&a, &inv_mod(&a)));
+        assert_eq!(a, from_be_bytes(&to_be_bytes(&a)));
+    }
+}
@@ -0,0 +1,95 @@
+//! [Secret], a wrapper that keeps a seed out of log lines by not printing it.
+
+use crate::Mnemonic;
+use core::fmt;
+
+/// A value that only shows itself through [Secret::expose], its `Debug` is
+/// `Secret(<redacted>)` and it has no `Display`, so a stray `{:?}` or `{}` in a log line
+/// can't leak the seed.
+///
+/// A wrapped [Mnemonic] is still wiped when dropped with the `zeroize` feature, and the
+/// `safe-default-display` feature redacts every [Mnemonic] rather than just wrapped ones.
+#[derive(Clone, Default)]
+pub struct Secret<T>(T);
+
+impl<T> Secret<T> {
+    /// Wrap `value`, same as [Secret::from].
+    pub fn new(value: T) -> Self {
+        Secret(value)
+    }
+
+    /// The wrapped value, to print or use it on purpose.
+    pub fn expose(&self) -> &T {
+        &self.0
+    }
+
+    /// Unwrap the value, which is then printable again.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: crate::Zeroize> crate::Zeroize for Secret<T> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<T: crate::ZeroizeOnDrop> crate::ZeroizeOnDrop for Secret<T> {}
+
+impl<T> From<T> for Secret<T> {
+    fn from(value: T) -> Self {
+        Secret(value)
+    }
+}
+
+impl From<Secret<Mnemonic>> for Mnemonic {
+    fn from(secret: Secret<Mnemonic>) -> Self {
+        secret.0
+    }
+}
+
+impl<T> fmt::Debug for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Secret(<redacted>)")
+    }
+}
+
+/// Compared with [Mnemonic::ct_eq], so comparing secrets doesn't leak how much of them match.
+impl PartialEq for Secret<Mnemonic> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl Eq for Secret<Mnemonic> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::secret::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn debug_is_redacted() {
+        let words = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let secret = Secret::from(Mnemonic::from_str(words).unwrap());
+        for debug in [format!("{secret:?}"), format!("{secret:#?}")] {
+            assert_eq!("Secret(<redacted>)", debug);
+            assert!(words.split(' ').all(|w| !debug.contains(w)));
+        }
+        let shares = vec![secret.clone(), Secret::new(Mnemonic::from([0u8; 16]))];
+        assert_eq!(
+            "[Secret(<redacted>), Secret(<redacted>)]",
+            format!("{shares:?}")
+        );
+
+        assert_eq!(words, secret.expose().reveal());
+        assert_eq!(secret, Secret::new(Mnemonic::from_str(words).unwrap()));
+        assert_ne!(secret, shares[1]);
+        let mnemonic: Mnemonic = secret.into();
+        assert_eq!(words, mnemonic.reveal());
+        assert_eq!(mnemonic, Secret::new(mnemonic.clone()).into_inner());
+    }
+}
@@ -0,0 +1,138 @@
+//! [SeedQR](https://github.com/SeedSigner/seedsigner/blob/dev/docs/seed_qr/README.md) numeric
+//! format: each word's index in the English wordlist as 4 zero padded digits, concatenated.
+
+use crate::{Error, Language, Mnemonic, SeedXorError};
+use alloc::{format, string::String};
+
+impl Mnemonic {
+    /// Parse SeedQR digits, 48 for 12 words or 96 for 24 words, into an English mnemonic.
+    ///
+    /// A 4 digit group that isn't a word index is reported as [Error::UnknownWord].
+    pub fn from_seedqr_digits(digits: &str) -> Result<Mnemonic, SeedXorError> {
+        Self::from_seedqr_digits_in(Language::English, digits)
+    }
+
+    /// [Mnemonic::from_seedqr_digits] with indices into the `language` wordlist, SeedQR itself
+    /// is only defined for English.
+    pub fn from_seedqr_digits_in(
+        language: Language,
+        digits: &str,
+    ) -> Result<Mnemonic, SeedXorError> {
+        if !digits.len().is_multiple_of(4) || !digits.is_ascii() {
+            return Err(Error::BadWordCount(digits.len() / 4).into());
+        }
+        let list = language.word_list();
+        let mut words = String::with_capacity(digits.len() * 2);
+        for (i, index) in digits.as_bytes().chunks(4).enumerate() {
+            let index = core::str::from_utf8(index)
+                .ok()
+                .filter(|index| index.bytes().all(|b| b.is_ascii_digit()))
+                .and_then(|index| index.parse::<usize>().ok())
+                .and_then(|index| list.get(index))
+                .ok_or(Error::UnknownWord(i))?;
+            words.push_str(index);
+            words.push(' ');
+        }
+        words.pop();
+        Ok(bip39::Mnemonic::parse_in_normalized(language, &words)?.into())
+    }
+
+    /// The SeedQR digits of this mnemonic, `word_count * 4` of them, using the indices into its
+    /// own language's wordlist.
+    pub fn to_seedqr_digits(&self) -> String {
+        let language = self.language();
+        let mut ret = String::with_capacity(self.word_count() * 4);
+        for word in self.word_iter() {
+            let index = language
+                .find_word(word)
+                .expect("word is in its own wordlist");
+            ret.push_str(&format!("{index:04}"));
+        }
+        ret
+    }
+}
+
+/// Whether `s` looks like SeedQR digits rather than words, no bip39 word contains a digit.
+pub(crate) fn is_seedqr_digits(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::seedqr::*;
+    use std::str::FromStr;
+
+    // Coldcard seed xor example
+    const SEED_24: &str = "romance wink lottery autumn shop bring dawn tongue range crater truth ability miss spice fitness easy legal release recall obey exchange recycle dragon room";
+    const DIGITS_24: &str = "150020141056012515890225044718271422040418700001113416760703055810191449143512150629143905281503";
+    // https://github.com/SeedSigner/seedsigner/blob/dev/docs/seed_qr/README.md
+    const SEED_12: &str =
+        "vacuum bridge buddy supreme exclude milk consider tail expand wasp pattern nuclear";
+    const DIGITS_12: &str = "192402220235174306311124037817700641198012901210";
+
+    #[test]
+    fn from_seedqr_digits() {
+        assert_eq!(
+            Mnemonic::from_str(SEED_24).unwrap(),
+            Mnemonic::from_seedqr_digits(DIGITS_24).unwrap()
+        );
+        assert_eq!(
+            Mnemonic::from_str(SEED_12).unwrap(),
+            Mnemonic::from_seedqr_digits(DIGITS_12).unwrap()
+        );
+
+        assert_eq!(
+            Err(Error::BadWordCount(11).into()),
+            Mnemonic::from_seedqr_digits(&DIGITS_12[1..])
+        );
+        let mut digits = DIGITS_12.to_owned();
+        digits.replace_range(8..12, "2048");
+        assert_eq!(
+            Err(Error::UnknownWord(2).into()),
+            Mnemonic::from_seedqr_digits(&digits)
+        );
+        digits.replace_range(8..12, "02a5");
+        assert_eq!(
+            Err(Error::UnknownWord(2).into()),
+            Mnemonic::from_seedqr_digits(&digits)
+        );
+    }
+
+    #[test]
+    fn to_seedqr_digits() {
+        for (seed, digits) in [(SEED_12, DIGITS_12), (SEED_24, DIGITS_24)] {
+            let seed = Mnemonic::from_str(seed).unwrap();
+            assert_eq!(digits, seed.to_seedqr_digits());
+            assert_eq!(seed.word_count() * 4, seed.to_seedqr_digits().len());
+            assert_eq!(
+                seed,
+                Mnemonic::from_seedqr_digits(&seed.to_seedqr_digits()).unwrap()
+            );
+        }
+
+        let seed = Mnemonic::from(
+            bip39::Mnemonic::from_entropy_in(Language::French, &[0x11u8; 16]).unwrap(),
+        );
+        let digits = seed.to_seedqr_digits();
+        assert_eq!(48, digits.len());
+        assert_eq!(
+            seed,
+            Mnemonic::from_seedqr_digits_in(Language::French, &digits).unwrap()
+        );
+    }
+
+    #[test]
+    fn from_str_accepts_seedqr_digits() {
+        assert_eq!(
+            Mnemonic::from_str(SEED_24).unwrap(),
+            Mnemonic::from_str(DIGITS_24).unwrap()
+        );
+        assert_eq!(
+            Mnemonic::from_str(SEED_12).unwrap(),
+            Mnemonic::from_str(&format!(" {DIGITS_12}\n")).unwrap()
+        );
+        assert!(!is_seedqr_digits(SEED_12));
+        assert!(!is_seedqr_digits(""));
+        assert!(Mnemonic::from_str("1924 0222").is_err());
+    }
+}
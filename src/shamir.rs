@@ -0,0 +1,298 @@
+//! Shamir's secret sharing of the entropy over GF(256), for k-of-n recovery where plain XOR
+//! shares are always n-of-n.
+//!
+//! Every byte of the entropy is the constant term of its own random polynomial of degree
+//! `k - 1`, and each share holds every polynomial evaluated at its `x`. Any `k` shares
+//! interpolate back to the entropy, fewer reveal nothing about it, and also can't tell that
+//! they are too few, they just combine to a different, random, seed.
+
+use crate::{wipe, EntropySource, Mnemonic, SeedXorError};
+use alloc::{vec, vec::Vec};
+use core::fmt;
+
+/// One share of [Mnemonic::split_threshold], the share's entropy is the polynomials evaluated
+/// at `x`, and needs to be kept along with it.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Share {
+    pub x: u8,
+    pub share: Mnemonic,
+}
+
+impl fmt::Display for Share {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.x, self.share)
+    }
+}
+
+/// Multiplication modulo the AES polynomial x^8 + x^4 + x^3 + x + 1.
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut ret = 0;
+    while b != 0 {
+        if b & 1 == 1 {
+            ret ^= a;
+        }
+        a = (a << 1) ^ (0x1b & (a >> 7).wrapping_neg());
+        b >>= 1;
+    }
+    ret
+}
+
+/// a^254 which is a^-1 for a != 0, as the multiplicative group has order 255.
+fn gf_inv(a: u8) -> u8 {
+    let mut ret = 1;
+    for _ in 0..254 {
+        ret = gf_mul(ret, a);
+    }
+    ret
+}
+
+impl Mnemonic {
+    /// Split into `n` shares any `k` of which recombine to this mnemonic with
+    /// [combine_threshold], see the [module docs](crate::shamir).
+    ///
+    /// `k` must be at least 2 and at most `n`, which must be at most 255, otherwise
+    /// [SeedXorError::InvalidThreshold].
+    #[cfg(feature = "std")]
+    pub fn split_threshold(&self, k: usize, n: usize) -> Result<Vec<Share>, SeedXorError> {
+        self.split_threshold_with_rng(k, n, &mut crate::OsEntropy)
+    }
+
+    /// [Mnemonic::split_threshold] with the random coefficients drawn from `rng`.
+    pub fn split_threshold_with_rng<R: EntropySource + ?Sized>(
+        &self,
+        k: usize,
+        n: usize,
+        rng: &mut R,
+    ) -> Result<Vec<Share>, SeedXorError> {
+        if k < 2 || k > n || n > 255 {
+            return Err(SeedXorError::InvalidThreshold { k, n });
+        }
+        let (mut entropy, entropy_len) = self.to_entropy_array();
+        let mut coefficients = vec![0u8; entropy_len * (k - 1)];
+        let mut ys = vec![0u8; entropy_len];
+        let ret = rng.fill_bytes(&mut coefficients).and_then(|_| {
+            (1..=n as u8)
+                .map(|x| {
+                    for (i, y) in ys.iter_mut().enumerate() {
+                        // Horner's method, highest degree first
+                        *y = coefficients[i * (k - 1)..(i + 1) * (k - 1)]
+                            .iter()
+                            .rev()
+                            .fold(0, |acc, c| gf_mul(acc, x) ^ c);
+                        *y = gf_mul(*y, x) ^ entropy[i];
+                    }
+                    Ok(Share {
+                        x,
+                        share: bip39::Mnemonic::from_entropy_in(self.language(), &ys)?.into(),
+                    })
+                })
+                .collect()
+        });
+        wipe(&mut entropy);
+        wipe(&mut coefficients);
+        wipe(&mut ys);
+        ret
+    }
+}
+
+/// Interpolate `shares` of [Mnemonic::split_threshold] back to the mnemonic, in the language of
+/// the first share.
+///
+/// This needs at least `k` shares, but can't tell when it has fewer, see the
+/// [module docs](crate::shamir). No shares is [SeedXorError::EmptyInput], shares of different
+/// lengths a [SeedXorError::LengthMismatch], and a share whose `x` is 0 or the same as an
+/// earlier share's [SeedXorError::DuplicateShare] with its index.
+pub fn combine_threshold(shares: &[Share]) -> Result<Mnemonic, SeedXorError> {
+    let first = shares.first().ok_or(SeedXorError::EmptyInput)?;
+    let (_, entropy_len) = first.share.to_entropy_array();
+    for (i, share) in shares.iter().enumerate() {
+        if share.x == 0 || shares[..i].iter().any(|s| s.x == share.x) {
+            return Err(SeedXorError::DuplicateShare(i));
+        }
+        let (_, len) = share.share.to_entropy_array();
+        if len != entropy_len {
+            return Err(SeedXorError::LengthMismatch {
+                expected: entropy_len,
+                got: len,
+            });
+        }
+    }
+    let mut entropy = vec![0u8; entropy_len];
+    for share in shares {
+        // Lagrange basis polynomial of this share at 0, subtraction is XOR in GF(256)
+        let basis = shares
+            .iter()
+            .filter(|s| s.x != share.x)
+            .fold(1, |acc, s| gf_mul(acc, gf_mul(s.x, gf_inv(s.x ^ share.x))));
+        let (mut ys, _) = share.share.to_entropy_array();
+        entropy
+            .iter_mut()
+            .zip(&ys)
+            .for_each(|(e, y)| *e ^= gf_mul(basis, *y));
+        wipe(&mut ys);
+    }
+    let ret = bip39::Mnemonic::from_entropy_in(first.share.language(), &entropy);
+    wipe(&mut entropy);
+    Ok(ret?.into())
+}
+
+/// [combine_threshold] of the first `k` shares with distinct `x`, returning the indices of
+/// the shares it did not need, those after them and any repeating an earlier `x`, for telling
+/// which of a pile of shares were surplus.
+///
+/// A `k` below 2 is [SeedXorError::InvalidThreshold], fewer than `k` distinct shares
+/// [SeedXorError::LengthMismatch] of `k` and the distinct shares, and a share whose `x` is 0
+/// [SeedXorError::DuplicateShare] with its index.
+pub fn combine_threshold_report(
+    shares: &[Share],
+    k: usize,
+) -> Result<(Mnemonic, Vec<usize>), SeedXorError> {
+    if k < 2 {
+        return Err(SeedXorError::InvalidThreshold { k, n: shares.len() });
+    }
+    let mut used: Vec<Share> = Vec::with_capacity(k);
+    let mut unused = Vec::new();
+    for (i, share) in shares.iter().enumerate() {
+        if share.x == 0 {
+            return Err(SeedXorError::DuplicateShare(i));
+        }
+        if used.len() == k || used.iter().any(|s| s.x == share.x) {
+            unused.push(i);
+        } else {
+            used.push(share.clone());
+        }
+    }
+    if used.len() < k {
+        return Err(SeedXorError::LengthMismatch {
+            expected: k,
+            got: used.len(),
+        });
+    }
+    Ok((combine_threshold(&used)?, unused))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::shamir::*;
+    #[cfg(feature = "std")]
+    use std::str::FromStr;
+
+    #[test]
+    fn gf256() {
+        // from FIPS 197
+        assert_eq!(0xc1, gf_mul(0x57, 0x83));
+        assert_eq!(0xfe, gf_mul(0x57, 0x13));
+        for a in 1..=255u8 {
+            assert_eq!(1, gf_mul(a, gf_inv(a)));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn threshold_shares() {
+        for seed in [
+            "silent toe meat possible chair blossom wait occur this worth option boy",
+            "romance wink lottery autumn shop bring dawn tongue range crater truth ability miss spice fitness easy legal release recall obey exchange recycle dragon room",
+        ] {
+            let seed = Mnemonic::from_str(seed).unwrap();
+            let shares = seed.split_threshold(3, 5).unwrap();
+            assert_eq!(
+                vec![1, 2, 3, 4, 5],
+                shares.iter().map(|s| s.x).collect::<Vec<_>>()
+            );
+            // every subset of 3 or more recovers, in any order, and smaller ones do not
+            for mask in 0u32..32 {
+                let subset: Vec<Share> = shares
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| mask & (1 << i) != 0)
+                    .map(|(_, s)| s.clone())
+                    .rev()
+                    .collect();
+                match subset.len() {
+                    0 => {}
+                    1 | 2 => assert_ne!(seed, combine_threshold(&subset).unwrap()),
+                    _ => assert_eq!(seed, combine_threshold(&subset).unwrap()),
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn threshold_report() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let seed = Mnemonic::from_str(seed).unwrap();
+        let shares = seed.split_threshold(3, 5).unwrap();
+
+        let four = [&shares[4], &shares[0], &shares[2], &shares[1]].map(Share::clone);
+        assert_eq!(
+            Ok((seed.clone(), vec![3])),
+            combine_threshold_report(&four, 3)
+        );
+        // a repeated x is surplus even before the threshold is reached
+        let repeated = [&shares[0], &shares[0], &shares[1], &shares[2]].map(Share::clone);
+        assert_eq!(
+            Ok((seed.clone(), vec![1])),
+            combine_threshold_report(&repeated, 3)
+        );
+        assert_eq!(
+            Ok((seed, vec![])),
+            combine_threshold_report(&shares[..3], 3)
+        );
+
+        assert_eq!(
+            Err(SeedXorError::LengthMismatch {
+                expected: 3,
+                got: 2
+            }),
+            combine_threshold_report(&repeated[..3], 3)
+        );
+        assert_eq!(
+            Err(SeedXorError::InvalidThreshold { k: 1, n: 4 }),
+            combine_threshold_report(&four, 1)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn threshold_errors() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let seed = Mnemonic::from_str(seed).unwrap();
+        assert_eq!(
+            Err(SeedXorError::InvalidThreshold { k: 1, n: 3 }),
+            seed.split_threshold(1, 3)
+        );
+        assert_eq!(
+            Err(SeedXorError::InvalidThreshold { k: 4, n: 3 }),
+            seed.split_threshold(4, 3)
+        );
+        assert_eq!(
+            Err(SeedXorError::InvalidThreshold { k: 2, n: 256 }),
+            seed.split_threshold(2, 256)
+        );
+        assert_eq!(255, seed.split_threshold(2, 255).unwrap().len());
+
+        let mut shares = seed.split_threshold(2, 3).unwrap();
+        assert_eq!(Err(SeedXorError::EmptyInput), combine_threshold(&[]));
+        shares[2].x = 1;
+        assert_eq!(
+            Err(SeedXorError::DuplicateShare(2)),
+            combine_threshold(&shares)
+        );
+        shares[2].x = 0;
+        assert_eq!(
+            Err(SeedXorError::DuplicateShare(2)),
+            combine_threshold(&shares)
+        );
+        shares[2].x = 3;
+        shares[2].share = Mnemonic::from([0u8; 32]);
+        assert_eq!(
+            Err(SeedXorError::LengthMismatch {
+                expected: 16,
+                got: 32
+            }),
+            combine_threshold(&shares)
+        );
+    }
+}
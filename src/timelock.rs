@@ -0,0 +1,149 @@
+//! Shares tagged with a time before which they should not be combined, for dead man's switch
+//! style backups.
+//!
+//! This is advisory only, enforced by this software and nothing else. Anyone holding every
+//! share can XOR them together with anything at any time, the lock just stops this crate from
+//! doing it early by accident.
+
+#[cfg(feature = "std")]
+use crate::SeedXorError;
+use crate::{Mnemonic, SeedXor};
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A share that [combine_time_locked] refuses to use before `not_before`, seconds since the
+/// unix epoch.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TimeLockedShare {
+    pub share: Mnemonic,
+    pub not_before: u64,
+}
+
+/// Returned by [combine_time_locked] when the shares can't be combined (yet).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TimeLockError {
+    /// There were no shares to combine.
+    NoShares,
+    /// The latest `not_before` of all the shares, which has not been reached.
+    Locked { not_before: u64 },
+}
+
+impl fmt::Display for TimeLockError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TimeLockError::NoShares => write!(f, "no shares to combine"),
+            TimeLockError::Locked { not_before } => {
+                write!(
+                    f,
+                    "shares are locked until {not_before} seconds after the epoch"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for TimeLockError {}
+
+#[cfg(feature = "std")]
+impl Mnemonic {
+    /// [Mnemonic::splitn] into `n` shares, the share at each index locked until the time at the
+    /// same index of `unlock_times`, which must have `n` entries or
+    /// [SeedXorError::LengthMismatch] is returned.
+    pub fn split_time_locked(
+        &self,
+        n: usize,
+        unlock_times: &[u64],
+    ) -> Result<Vec<TimeLockedShare>, SeedXorError> {
+        if unlock_times.len() != n {
+            return Err(SeedXorError::LengthMismatch {
+                expected: n,
+                got: unlock_times.len(),
+            });
+        }
+        Ok(self
+            .clone()
+            .splitn(n)?
+            .into_iter()
+            .zip(unlock_times)
+            .map(|(share, not_before)| TimeLockedShare {
+                share,
+                not_before: *not_before,
+            })
+            .collect())
+    }
+}
+
+/// Combine `shares` if the system clock is at or past every share's `not_before`.
+#[cfg(feature = "std")]
+pub fn combine_time_locked(shares: &[TimeLockedShare]) -> Result<Mnemonic, TimeLockError> {
+    // a clock before 1970 is treated as 0, which just keeps everything locked
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    combine_time_locked_at(shares, now)
+}
+
+/// [combine_time_locked] as if the time was `now`, seconds since the unix epoch.
+pub fn combine_time_locked_at(
+    shares: &[TimeLockedShare],
+    now: u64,
+) -> Result<Mnemonic, TimeLockError> {
+    let not_before = shares
+        .iter()
+        .map(|s| s.not_before)
+        .max()
+        .ok_or(TimeLockError::NoShares)?;
+    if now < not_before {
+        return Err(TimeLockError::Locked { not_before });
+    }
+    let shares: Vec<Mnemonic> = shares.iter().map(|s| s.share.clone()).collect();
+    Mnemonic::xor_all(&shares).map_err(|_| TimeLockError::NoShares)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::timelock::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn time_locked_shares() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let seed = Mnemonic::from_str(seed).unwrap();
+
+        let shares = seed.split_time_locked(3, &[100, 300, 200]).unwrap();
+        assert_eq!(
+            vec![100, 300, 200],
+            shares.iter().map(|s| s.not_before).collect::<Vec<_>>()
+        );
+        for now in [0, 100, 299] {
+            assert_eq!(
+                Err(TimeLockError::Locked { not_before: 300 }),
+                combine_time_locked_at(&shares, now)
+            );
+        }
+        assert_eq!(Ok(seed.clone()), combine_time_locked_at(&shares, 300));
+        assert_eq!(Ok(seed.clone()), combine_time_locked_at(&shares, u64::MAX));
+        // well in the past by the time this runs
+        assert_eq!(Ok(seed.clone()), combine_time_locked(&shares));
+
+        assert_eq!(
+            Err(TimeLockError::Locked {
+                not_before: u64::MAX
+            }),
+            combine_time_locked(&seed.split_time_locked(2, &[0, u64::MAX]).unwrap())
+        );
+        assert_eq!(
+            Err(TimeLockError::NoShares),
+            combine_time_locked_at(&[], u64::MAX)
+        );
+        assert_eq!(
+            Err(SeedXorError::LengthMismatch {
+                expected: 3,
+                got: 2
+            }),
+            seed.split_time_locked(3, &[0, 0])
+        );
+    }
+}
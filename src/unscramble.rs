@@ -0,0 +1,148 @@
+//! Unscrambling seed words written down in an unknown order, with some of their positions
+//! known for certain.
+
+use crate::{expand_words_in, Error, Language, Mnemonic, SeedXorError};
+use alloc::{string::String, vec::Vec};
+
+/// Every valid mnemonic from some order of `words` with each of `fixed` at its position,
+/// counted from 0, so only `words.len()!` orders are tried rather than those of every word.
+///
+/// Each of `words` may hold several words, or prefixes of words, which stay together, and the
+/// other words fill the positions that aren't fixed in order. An unknown word is
+/// [Error::UnknownWord] of its index among `words` then `fixed`, a position past the end
+/// [SeedXorError::IndexOutOfRange], one fixed twice [SeedXorError::DuplicatePosition], and an
+/// invalid word count [Error::BadWordCount].
+pub fn unscramble_with_anchors(
+    words: &[String],
+    fixed: &[(usize, String)],
+) -> Result<Vec<Mnemonic>, SeedXorError> {
+    let all: Vec<&str> = words
+        .iter()
+        .chain(fixed.iter().map(|(_, word)| word))
+        .map(String::as_str)
+        .collect();
+    let all = all.join(" ");
+    let language = bip39::Mnemonic::language_of(&all).unwrap_or(Language::English);
+    let indices = |s: &str| -> Result<Vec<u16>, usize> {
+        let expanded = expand_words_in(language, s).map_err(|_| 0usize)?;
+        expanded
+            .split_whitespace()
+            .map(|w| language.find_word(w).ok_or(0usize))
+            .collect()
+    };
+    let mut parts = Vec::with_capacity(words.len());
+    for (i, word) in words.iter().enumerate() {
+        parts.push(indices(word).map_err(|_| Error::UnknownWord(i))?);
+    }
+    let mut anchors = Vec::with_capacity(fixed.len());
+    for (i, (position, word)) in fixed.iter().enumerate() {
+        match indices(word).as_deref() {
+            Ok([index]) => anchors.push((*position, *index)),
+            _ => return Err(Error::UnknownWord(words.len() + i).into()),
+        }
+    }
+    let word_count = parts.iter().map(Vec::len).sum::<usize>() + anchors.len();
+    anchors.sort_unstable();
+    for (i, (position, _)) in anchors.iter().enumerate() {
+        if *position >= word_count {
+            return Err(SeedXorError::IndexOutOfRange {
+                index: *position,
+                len: word_count,
+            });
+        }
+        if i > 0 && anchors[i - 1].0 == *position {
+            return Err(SeedXorError::DuplicatePosition(*position));
+        }
+    }
+
+    let mut order: Vec<usize> = (0..parts.len()).collect();
+    let mut heap = permutohedron::Heap::new(&mut order);
+    let mut buf = Vec::with_capacity(word_count);
+    let mut ret = Vec::new();
+    while let Some(order) = heap.next_permutation() {
+        buf.clear();
+        for i in order.iter() {
+            buf.extend_from_slice(&parts[*i]);
+        }
+        // ascending, so earlier anchors are already in place
+        for (position, index) in &anchors {
+            buf.insert(*position, *index);
+        }
+        match Mnemonic::from_word_indices_in(language, &buf) {
+            Ok(mnemonic) => ret.push(mnemonic),
+            Err(SeedXorError::Bip39(Error::InvalidChecksum)) => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::unscramble::*;
+    use std::str::FromStr;
+
+    const SEED: &str = "silent toe meat possible chair blossom wait occur this worth option boy";
+
+    fn strings(words: &[&str]) -> Vec<String> {
+        words.iter().map(|w| w.to_string()).collect()
+    }
+
+    #[test]
+    fn anchors() {
+        let seed = Mnemonic::from_str(SEED).unwrap();
+        let fixed = vec![(2, "meat".to_owned()), (11, "boy".to_owned())];
+        // pairs of words rather than 10 single ones to keep the orders few
+        let parts = strings(&[
+            "silent toe",
+            "possible chair",
+            "blossom wait",
+            "occur this",
+            "worth option",
+        ]);
+        let found = unscramble_with_anchors(&parts, &fixed).unwrap();
+        assert!(found.contains(&seed), "{found:?}");
+        for mnemonic in &found {
+            let words: Vec<&str> = mnemonic.word_iter().collect();
+            assert_eq!("meat", words[2]);
+            assert_eq!("boy", words[11]);
+        }
+
+        // no anchors is the same as a plain unscramble
+        let parts = strings(&[
+            "silent toe meat",
+            "possible chair blossom",
+            "wait occur this",
+            "worth option boy",
+        ]);
+        let found = unscramble_with_anchors(&parts, &[]).unwrap();
+        assert!(found.contains(&seed), "{found:?}");
+        assert!(found.len() < 24);
+
+        assert_eq!(
+            Err(Error::BadWordCount(5).into()),
+            unscramble_with_anchors(&parts[0..1], &[(0, "boy".into()), (1, "toe".into())])
+        );
+    }
+
+    #[test]
+    fn anchor_errors() {
+        let parts = strings(&["silent toe meat possible chair blossom wait occur this worth"]);
+        assert_eq!(
+            Err(SeedXorError::IndexOutOfRange { index: 12, len: 12 }),
+            unscramble_with_anchors(&parts, &[(12, "option".into()), (0, "boy".into())])
+        );
+        assert_eq!(
+            Err(SeedXorError::DuplicatePosition(3)),
+            unscramble_with_anchors(&parts, &[(3, "option".into()), (3, "boy".into())])
+        );
+        assert_eq!(
+            Err(Error::UnknownWord(2).into()),
+            unscramble_with_anchors(&parts, &[(3, "option".into()), (4, "notaword".into())])
+        );
+        assert_eq!(
+            Err(Error::UnknownWord(0).into()),
+            unscramble_with_anchors(&strings(&["silent tow"]), &[])
+        );
+    }
+}
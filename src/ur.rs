@@ -0,0 +1,231 @@
+//! [UR](https://github.com/BlockchainCommons/Research/blob/master/papers/bcr-2020-005-ur.md)
+//! `ur:crypto-seed` encoding of the entropy, as exchanged over QR codes by hardware wallets
+//! like Passport and Coldcard.
+//!
+//! The payload is the
+//! [crypto-seed](https://github.com/BlockchainCommons/Research/blob/master/papers/bcr-2020-006-urtypes.md)
+//! CBOR map, `{1: entropy}`, followed by its CRC32, with each byte written as the first and
+//! last letter of its [byteword](https://github.com/BlockchainCommons/Research/blob/master/papers/bcr-2020-012-bytewords.md).
+//! Even 32 bytes of entropy fit in a single part, so multi part URs are not supported.
+
+use crate::{visual::crc32, Error, Mnemonic, SeedXorError};
+use alloc::{format, string::String, vec, vec::Vec};
+
+const PREFIX: &str = "ur:crypto-seed/";
+/// The 256 bytewords, 4 letters each, in byte order.
+const BYTEWORDS: &[u8] = concat!(
+    "ableacidalsoapexaquaarchatomauntawayaxisbackbaldbarnbeltbetabiasbluebodybragbrewbulbbuzzcalmcashcatschefcityclawcodecolacookcost",
+    "cruxcurlcuspcyandarkdatadaysdelidicedietdoordowndrawdropdrumdulldutyeacheasyechoedgeepicevenexamexiteyesfactfairfernfigsfilmfish",
+    "fizzflapflewfluxfoxyfreefrogfuelfundgalagamegeargemsgiftgirlglowgoodgraygrimgurugushgyrohalfhanghardhawkheathelphighhillholyhope",
+    "hornhutsicedideaidleinchinkyintoirisironitemjadejazzjoinjoltjowljudojugsjumpjunkjurykeepkenokeptkeyskickkilnkingkitekiwiknoblamb",
+    "lavalazyleaflegsliarlimplionlistlogoloudloveluaulucklungmainmanymathmazememomenumeowmildmintmissmonknailnavyneednewsnextnoonnote",
+    "numbobeyoboeomitonyxopenovalowlspaidpartpeckplaypluspoempoolposepuffpumapurrquadquizraceramprealredorichroadrockroofrubyruinruns",
+    "rustsafesagascarsetssilkskewslotsoapsolosongstubsurfswantacotasktaxitenttiedtimetinytoiltombtoystriptunatwinuglyundouniturgeuser",
+    "vastveryvetovialvibeviewvisavoidvowswallwandwarmwaspwavewaxywebswhatwhenwhizwolfworkyankyawnyellyogayurtzapszerozestzinczonezoom",
+)
+.as_bytes();
+
+/// Minimal bytewords of `data` followed by its CRC32.
+fn bytewords_encode(data: &[u8]) -> String {
+    data.iter()
+        .chain(&crc32(data.iter().copied()).to_be_bytes())
+        .flat_map(|b| {
+            let word = &BYTEWORDS[*b as usize * 4..*b as usize * 4 + 4];
+            [word[0] as char, word[3] as char]
+        })
+        .collect()
+}
+
+/// Decode minimal bytewords, a pair of letters that isn't one is [SeedXorError::InvalidByteword]
+/// with its index, and a bad CRC32 [Error::InvalidChecksum].
+fn bytewords_decode(s: &str) -> Result<Vec<u8>, SeedXorError> {
+    let s = s.as_bytes();
+    if !s.len().is_multiple_of(2) {
+        return Err(SeedXorError::InvalidByteword(s.len() / 2));
+    }
+    let data = s
+        .chunks(2)
+        .enumerate()
+        .map(|(i, pair)| {
+            BYTEWORDS
+                .chunks(4)
+                .position(|word| word[0] == pair[0] && word[3] == pair[1])
+                .map(|b| b as u8)
+                .ok_or(SeedXorError::InvalidByteword(i))
+        })
+        .collect::<Result<Vec<u8>, SeedXorError>>()?;
+    if data.len() < 4 {
+        return Err(Error::InvalidChecksum.into());
+    }
+    let (data, checksum) = data.split_at(data.len() - 4);
+    if crc32(data.iter().copied()).to_be_bytes() != *checksum {
+        return Err(Error::InvalidChecksum.into());
+    }
+    Ok(data.to_vec())
+}
+
+/// Read the major type and argument of the CBOR data item at the start of `data`, advancing it.
+fn cbor_head(data: &mut &[u8]) -> Option<(u8, u64)> {
+    let (first, rest) = data.split_first()?;
+    let len = match first & 0x1f {
+        0..=23 => 0,
+        24 => 1,
+        25 => 2,
+        26 => 4,
+        27 => 8,
+        _ => return None,
+    };
+    let arg = rest.get(..len)?;
+    *data = &rest[len..];
+    let arg = match len {
+        0 => (first & 0x1f) as u64,
+        _ => arg.iter().fold(0, |acc, b| acc << 8 | *b as u64),
+    };
+    Some((first >> 5, arg))
+}
+
+/// Read a whole CBOR data item from the start of `data`, returning the contents of byte and
+/// text strings. Only the integers, strings and tags crypto-seed uses are understood.
+fn cbor_item<'a>(data: &mut &'a [u8]) -> Option<&'a [u8]> {
+    match cbor_head(data)? {
+        (0 | 1, _) => Some(&[]),
+        (2 | 3, len) => {
+            let len = usize::try_from(len).ok().filter(|len| *len <= data.len())?;
+            let (ret, rest) = data.split_at(len);
+            *data = rest;
+            Some(ret)
+        }
+        (6, _) => cbor_item(data),
+        _ => None,
+    }
+}
+
+impl Mnemonic {
+    /// The entropy as a `ur:crypto-seed`, see the [module docs](crate::ur).
+    pub fn to_ur(&self) -> String {
+        let (entropy, entropy_len) = self.to_entropy_array();
+        // map of 1 pair, key 1, byte string of entropy_len bytes
+        let mut cbor = vec![0xa1, 0x01];
+        if entropy_len < 24 {
+            cbor.push(0x40 | entropy_len as u8);
+        } else {
+            cbor.extend_from_slice(&[0x58, entropy_len as u8]);
+        }
+        cbor.extend_from_slice(&entropy[0..entropy_len]);
+        let ret = format!("{PREFIX}{}", bytewords_encode(&cbor));
+        crate::wipe(&mut cbor);
+        ret
+    }
+
+    /// Parse a `ur:crypto-seed`, in either case, back into an English [Mnemonic].
+    ///
+    /// A pair of letters that isn't a byteword is [SeedXorError::InvalidByteword] of its index, a
+    /// bad CRC32 [Error::InvalidChecksum] and entropy of an invalid length
+    /// [Error::BadEntropyBitCount], anything else malformed, including other UR types,
+    /// [SeedXorError::InvalidUr].
+    pub fn from_ur(s: &str) -> Result<Mnemonic, SeedXorError> {
+        let bad = SeedXorError::InvalidUr;
+        let s = s.trim().to_lowercase();
+        let cbor = bytewords_decode(s.strip_prefix(PREFIX).ok_or(bad)?)?;
+        let mut data = &cbor[..];
+        let pairs = match cbor_head(&mut data) {
+            Some((5, pairs)) => pairs,
+            _ => return Err(bad),
+        };
+        let mut entropy = None;
+        for _ in 0..pairs {
+            let key = cbor_head(&mut data).ok_or(bad)?;
+            let value = cbor_item(&mut data).ok_or(bad)?;
+            if key == (0, 1) {
+                entropy = Some(value);
+            }
+        }
+        if !data.is_empty() {
+            return Err(bad);
+        }
+        Mnemonic::from_entropy(entropy.ok_or(bad)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ur::*;
+    #[cfg(feature = "std")]
+    use std::str::FromStr;
+
+    // https://github.com/BlockchainCommons/Research/blob/master/papers/bcr-2020-006-urtypes.md
+    const FIXTURE: &str =
+        "ur:crypto-seed/oeadgdstaslplabghydrpfmkbggufgludprfgmaotpiecffltnlpqdenos";
+    const FIXTURE_ENTROPY: [u8; 16] = [
+        0xc7, 0x09, 0x85, 0x80, 0x12, 0x5e, 0x2a, 0xb0, 0x98, 0x12, 0x53, 0x46, 0x8b, 0x2d, 0xbc,
+        0x52,
+    ];
+
+    #[test]
+    fn from_ur_fixture() {
+        let seed = Mnemonic::from(FIXTURE_ENTROPY);
+        // the fixture also carries a birthdate, which is skipped
+        assert_eq!(seed, Mnemonic::from_ur(FIXTURE).unwrap());
+        assert_eq!(seed, Mnemonic::from_ur(&FIXTURE.to_uppercase()).unwrap());
+        assert_eq!(
+            "ur:crypto-seed/oyadgdstaslplabghydrpfmkbggufgludprfgmamdpwmox",
+            seed.to_ur()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn ur_roundtrip() {
+        let seed = "romance wink lottery autumn shop bring dawn tongue range crater truth ability miss spice fitness easy legal release recall obey exchange recycle dragon room";
+        let seed = Mnemonic::from_str(seed).unwrap();
+        assert_eq!(seed, Mnemonic::from_ur(&seed.to_ur()).unwrap());
+        for share in seed.splitn(3).unwrap() {
+            assert_eq!(share, Mnemonic::from_ur(&share.to_ur()).unwrap());
+        }
+        let seed = Mnemonic::from_entropy(&[0x42u8; 24]).unwrap();
+        assert_eq!(seed, Mnemonic::from_ur(&seed.to_ur()).unwrap());
+    }
+
+    #[test]
+    fn from_ur_errors() {
+        let mut corrupted = FIXTURE.to_owned();
+        corrupted.replace_range(20..22, "ad");
+        assert_eq!(
+            Err(Error::InvalidChecksum.into()),
+            Mnemonic::from_ur(&corrupted)
+        );
+        corrupted.replace_range(20..22, "qq");
+        assert_eq!(
+            Err(SeedXorError::InvalidByteword(2)),
+            Mnemonic::from_ur(&corrupted)
+        );
+        assert_eq!(
+            Err(SeedXorError::InvalidUr),
+            Mnemonic::from_ur(&FIXTURE.replace("crypto-seed", "crypto-hdkey"))
+        );
+        // a bare byte string rather than a map
+        assert_eq!(
+            Err(SeedXorError::InvalidUr),
+            Mnemonic::from_ur(&format!(
+                "{PREFIX}{}",
+                bytewords_encode(&[0x42, 0x01, 0x02])
+            ))
+        );
+        // map without the entropy
+        assert_eq!(
+            Err(SeedXorError::InvalidUr),
+            Mnemonic::from_ur(&format!(
+                "{PREFIX}{}",
+                bytewords_encode(&[0xa1, 0x02, 0x01])
+            ))
+        );
+        // entropy of a length no word count has
+        assert_eq!(
+            Err(Error::BadEntropyBitCount(24).into()),
+            Mnemonic::from_ur(&format!(
+                "{PREFIX}{}",
+                bytewords_encode(&[0xa1, 0x01, 0x43, 0x01, 0x02, 0x03])
+            ))
+        );
+    }
+}
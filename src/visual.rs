@@ -0,0 +1,309 @@
+//! Shares drawn as black and white PNG grids of their entropy bits, one byte per row, black for
+//! a 1 bit. XORing the pixels of every share's image gives the seed's grid, which
+//! [combine_bit_pngs] decodes back into the mnemonic.
+//!
+//! Limitations:
+//! - physically overlaying printed transparencies shows black wherever *any* share is black,
+//!   that is OR not XOR, so the overlay alone does not reveal the seed, the XOR has to be done
+//!   digitally, or by hand cell by cell
+//! - only PNGs written by [Mnemonic::to_bit_png] can be decoded, they are stored uncompressed
+//!   which is tiny at this size, any other PNG, or one that has been re-saved by an image
+//!   editor, is rejected
+//! - only the entropy is drawn, so the image of a non-English mnemonic decodes to English
+
+use crate::{Error, Mnemonic, SeedXorError};
+use alloc::{vec, vec::Vec};
+
+/// Pixels per side of the square drawn for each bit.
+const CELL: usize = 8;
+const WIDTH: usize = 8 * CELL;
+/// Bytes per scanline of a 1 bit per pixel image, excluding the filter byte.
+const ROW_BYTES: usize = WIDTH / 8;
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+/// Largest stored deflate block.
+const MAX_STORED: usize = 0xffff;
+
+pub(crate) fn crc32(data: impl IntoIterator<Item = u8>) -> u32 {
+    !data.into_iter().fold(!0u32, |crc, b| {
+        (0..8).fold(crc ^ b as u32, |crc, _| {
+            (crc >> 1) ^ (0xedb88320 & (crc & 1).wrapping_neg())
+        })
+    })
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (a, b) = data.iter().fold((1u32, 0u32), |(a, b), d| {
+        let a = (a + *d as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+    (b << 16) | a
+}
+
+fn push_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(kind.iter().chain(data).copied());
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// zlib stream of `data` in stored (uncompressed) deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut ret = vec![0x78, 0x01];
+    let mut blocks = data.chunks(MAX_STORED).peekable();
+    if blocks.peek().is_none() {
+        ret.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        ret.push(blocks.peek().is_none() as u8);
+        let len = block.len() as u16;
+        ret.extend_from_slice(&len.to_le_bytes());
+        ret.extend_from_slice(&(!len).to_le_bytes());
+        ret.extend_from_slice(block);
+    }
+    ret.extend_from_slice(&adler32(data).to_be_bytes());
+    ret
+}
+
+/// Inverse of [zlib_stored], nothing else is accepted.
+fn unzlib_stored(data: &[u8]) -> Result<Vec<u8>, SeedXorError> {
+    let bad = SeedXorError::InvalidPng;
+    if data.len() < 6
+        || data[0] & 0x0f != 8
+        || !u16::from_be_bytes([data[0], data[1]]).is_multiple_of(31)
+    {
+        return Err(bad);
+    }
+    let (mut rest, adler) = data[2..].split_at(data.len() - 6);
+    let mut ret = Vec::new();
+    loop {
+        let (header, len, nlen) = match rest {
+            [header, l0, l1, n0, n1, ..] => (
+                *header,
+                u16::from_le_bytes([*l0, *l1]),
+                u16::from_le_bytes([*n0, *n1]),
+            ),
+            _ => return Err(bad),
+        };
+        // only stored blocks, whose 3 header bits are final then type 00
+        if header & !1 != 0 || len != !nlen || rest.len() < 5 + len as usize {
+            return Err(bad);
+        }
+        ret.extend_from_slice(&rest[5..5 + len as usize]);
+        rest = &rest[5 + len as usize..];
+        if header == 1 {
+            break;
+        }
+    }
+    if !rest.is_empty() || adler32(&ret).to_be_bytes() != adler {
+        return Err(Error::InvalidChecksum.into());
+    }
+    Ok(ret)
+}
+
+/// The entropy bits of an image written by [Mnemonic::to_bit_png].
+fn decode_bits(png: &[u8]) -> Result<Vec<u8>, SeedXorError> {
+    let bad = SeedXorError::InvalidPng;
+    let mut rest = png.strip_prefix(&SIGNATURE).ok_or(bad)?;
+    let mut size = None;
+    let mut idat = Vec::new();
+    loop {
+        if rest.len() < 12 {
+            return Err(bad);
+        }
+        let len = u32::from_be_bytes(rest[0..4].try_into().expect("4 bytes")) as usize;
+        if rest.len() < 12 + len {
+            return Err(bad);
+        }
+        let (kind, data) = (&rest[4..8], &rest[8..8 + len]);
+        let crc = u32::from_be_bytes(rest[8 + len..12 + len].try_into().expect("4 bytes"));
+        if crc != crc32(rest[4..8 + len].iter().copied()) {
+            return Err(Error::InvalidChecksum.into());
+        }
+        rest = &rest[12 + len..];
+        match kind {
+            b"IHDR" => {
+                // width, height, bit depth 1, grayscale, no interlace
+                if len != 13 || data[8..13] != [1, 0, 0, 0, 0] {
+                    return Err(bad);
+                }
+                let width = u32::from_be_bytes(data[0..4].try_into().expect("4 bytes"));
+                let height = u32::from_be_bytes(data[4..8].try_into().expect("4 bytes"));
+                if width as usize != WIDTH || !(height as usize).is_multiple_of(CELL) {
+                    return Err(bad);
+                }
+                size = Some(height as usize / CELL);
+            }
+            b"IDAT" => idat.extend_from_slice(data),
+            b"IEND" => break,
+            _ => {}
+        }
+    }
+    let entropy_len = size.ok_or(bad)?;
+    let pixels = unzlib_stored(&idat)?;
+    if pixels.len() != entropy_len * CELL * (1 + ROW_BYTES) {
+        return Err(bad);
+    }
+    let mut ret = vec![0u8; entropy_len];
+    for (y, line) in pixels.chunks(1 + ROW_BYTES).enumerate() {
+        // only filter type None, which is all to_bit_png writes
+        if line[0] != 0 {
+            return Err(bad);
+        }
+        // every pixel of a cell must agree
+        let byte = (0..8).fold(0u8, |byte, bit| {
+            let black = line[1 + bit] == 0;
+            (byte << 1) | black as u8
+        });
+        if line[1..].iter().any(|b| *b != 0 && *b != 0xff) {
+            return Err(bad);
+        }
+        if y % CELL == 0 {
+            ret[y / CELL] = byte;
+        } else if ret[y / CELL] != byte {
+            return Err(bad);
+        }
+    }
+    Ok(ret)
+}
+
+impl Mnemonic {
+    /// Draw the entropy bits as a PNG, one byte per row of 8 black (1) or white (0) squares,
+    /// see the [module docs](self) for how these combine and their limitations.
+    pub fn to_bit_png(&self) -> Vec<u8> {
+        let (entropy, entropy_len) = self.to_entropy_array();
+        let height = entropy_len * CELL;
+
+        let mut pixels = Vec::with_capacity(height * (1 + ROW_BYTES));
+        for byte in &entropy[0..entropy_len] {
+            for _ in 0..CELL {
+                // filter type None, then since a cell is exactly 8 pixels each bit is a whole
+                // byte, and 0 is black in grayscale
+                pixels.push(0);
+                pixels.extend(
+                    (0..8)
+                        .rev()
+                        .map(|bit| [0xff, 0x00][(byte >> bit) as usize & 1]),
+                );
+            }
+        }
+
+        let mut ihdr = Vec::with_capacity(13);
+        ihdr.extend_from_slice(&(WIDTH as u32).to_be_bytes());
+        ihdr.extend_from_slice(&(height as u32).to_be_bytes());
+        ihdr.extend_from_slice(&[1, 0, 0, 0, 0]);
+
+        let mut png = SIGNATURE.to_vec();
+        push_chunk(&mut png, b"IHDR", &ihdr);
+        push_chunk(&mut png, b"IDAT", &zlib_stored(&pixels));
+        push_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+
+    /// [Mnemonic::splitn] into `n` shares, each drawn with [Mnemonic::to_bit_png].
+    #[cfg(feature = "std")]
+    pub fn split_visual_shares(&self, n: usize) -> Result<Vec<Vec<u8>>, SeedXorError> {
+        Ok(self
+            .clone()
+            .splitn(n)?
+            .iter()
+            .map(Mnemonic::to_bit_png)
+            .collect())
+    }
+}
+
+/// XOR the bit grids of PNGs written by [Mnemonic::to_bit_png] and decode the result into an
+/// English mnemonic.
+///
+/// Images that weren't written by [Mnemonic::to_bit_png] are rejected with
+/// [SeedXorError::InvalidPng], or [Error::InvalidChecksum] if they are corrupt, images of
+/// different sizes with [SeedXorError::LengthMismatch] of the entropy bytes, and no images
+/// with [SeedXorError::EmptyInput].
+pub fn combine_bit_pngs<T: AsRef<[u8]>>(pngs: &[T]) -> Result<Mnemonic, SeedXorError> {
+    let mut entropy: Option<Vec<u8>> = None;
+    for png in pngs {
+        let bits = decode_bits(png.as_ref())?;
+        match entropy.as_mut() {
+            None => entropy = Some(bits),
+            Some(entropy) if entropy.len() == bits.len() => {
+                entropy.iter_mut().zip(bits).for_each(|(e, b)| *e ^= b)
+            }
+            Some(entropy) => {
+                return Err(SeedXorError::LengthMismatch {
+                    expected: entropy.len(),
+                    got: bits.len(),
+                })
+            }
+        }
+    }
+    Mnemonic::from_entropy(&entropy.ok_or(SeedXorError::EmptyInput)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::visual::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn checksums() {
+        assert_eq!(0xcbf43926, crc32(*b"123456789"));
+        assert_eq!(0x11e60398, adler32(b"Wikipedia"));
+        let data: Vec<u8> = (0..MAX_STORED * 2 + 7).map(|i| i as u8).collect();
+        assert_eq!(data, unzlib_stored(&zlib_stored(&data)).unwrap());
+        assert!(unzlib_stored(&zlib_stored(&[])).unwrap().is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn visual_shares_combine() {
+        for seed in [
+            "silent toe meat possible chair blossom wait occur this worth option boy",
+            "romance wink lottery autumn shop bring dawn tongue range crater truth ability miss spice fitness easy legal release recall obey exchange recycle dragon room",
+        ] {
+            let seed = Mnemonic::from_str(seed).unwrap();
+            assert_eq!(seed, combine_bit_pngs(&[seed.to_bit_png()]).unwrap());
+            for n in 2..=4 {
+                let pngs = seed.split_visual_shares(n).unwrap();
+                assert_eq!(n, pngs.len());
+                assert_eq!(seed, combine_bit_pngs(&pngs).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn visual_shares_reject_bad_pngs() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let png = Mnemonic::from_str(seed).unwrap().to_bit_png();
+
+        // flip one pixel
+        let mut corrupt = png.clone();
+        let pixel = corrupt.len() - 12 - 4 - 20;
+        corrupt[pixel] ^= 0xff;
+        assert_eq!(
+            Err(Error::InvalidChecksum.into()),
+            combine_bit_pngs(&[corrupt])
+        );
+        assert_eq!(
+            Err(SeedXorError::InvalidPng),
+            combine_bit_pngs(&[&png[1..]])
+        );
+        assert_eq!(
+            Err(SeedXorError::InvalidPng),
+            combine_bit_pngs(&[&png[..png.len() - 1]])
+        );
+        assert_eq!(
+            Err(SeedXorError::EmptyInput),
+            combine_bit_pngs::<&[u8]>(&[])
+        );
+
+        let long = "romance wink lottery autumn shop bring dawn tongue range crater truth ability miss spice fitness easy legal release recall obey exchange recycle dragon room";
+        let long = Mnemonic::from_str(long).unwrap().to_bit_png();
+        assert_eq!(
+            Err(SeedXorError::LengthMismatch {
+                expected: 16,
+                got: 32
+            }),
+            combine_bit_pngs(&[png, long])
+        );
+    }
+}
@@ -0,0 +1,200 @@
+//! Custom 2048 word lists, for wallets that map word indices to words of their own rather than
+//! to one of the bip39 [Language]s.
+//!
+//! Only the mapping between words and indices changes, the checksum is standard bip39. A
+//! mnemonic of custom words is held as the [Mnemonic] with the same word indices, which
+//! splits and combines like any other, and turned back into custom words with
+//! [Mnemonic::reveal_with_list].
+
+use crate::{Error, Language, Mnemonic, SeedXorError};
+use alloc::{string::String, vec::Vec};
+
+/// 2048 distinct words, none empty or containing whitespace, see the
+/// [module docs](crate::wordlist).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Wordlist<'a> {
+    words: &'a [&'a str; 2048],
+}
+
+impl<'a> Wordlist<'a> {
+    /// Check `words` make a wordlist, an empty word, one containing whitespace or a repeat of
+    /// an earlier one is [Error::UnknownWord] of its index.
+    pub fn new(words: &'a [&'a str; 2048]) -> Result<Self, SeedXorError> {
+        for (i, word) in words.iter().enumerate() {
+            if word.is_empty() || word.contains(char::is_whitespace) || words[..i].contains(word) {
+                return Err(Error::UnknownWord(i).into());
+            }
+        }
+        Ok(Wordlist { words })
+    }
+
+    /// The words, in index order.
+    pub fn words(&self) -> &'a [&'a str; 2048] {
+        self.words
+    }
+
+    /// The index of `word`, which must be whole.
+    pub fn find_word(&self, word: &str) -> Option<u16> {
+        self.words.iter().position(|w| *w == word).map(|i| i as u16)
+    }
+
+    /// Expand words shortened to unique prefixes back into whole words like
+    /// [crate::expand_words_in], a word that is neither whole nor a unique prefix is
+    /// [Error::UnknownWord]. Custom words are matched as they are, without lowercasing.
+    pub fn expand_words(&self, seed: &str) -> Result<String, SeedXorError> {
+        Ok(self.indices(seed)?.iter().fold(String::new(), |mut s, i| {
+            if !s.is_empty() {
+                s.push(' ');
+            }
+            s.push_str(self.words[*i as usize]);
+            s
+        }))
+    }
+
+    /// The index of each whole word or unique prefix of `seed`.
+    fn indices(&self, seed: &str) -> Result<Vec<u16>, SeedXorError> {
+        seed.split_whitespace()
+            .enumerate()
+            .map(|(i, prefix)| {
+                self.find_word(prefix)
+                    .or_else(|| {
+                        let words = self.words.iter().enumerate();
+                        let mut matches = words.filter(|(_, w)| w.starts_with(prefix));
+                        match (matches.next(), matches.next()) {
+                            (Some((index, _)), None) => Some(index as u16),
+                            _ => None,
+                        }
+                    })
+                    .ok_or(Error::UnknownWord(i).into())
+            })
+            .collect()
+    }
+}
+
+/// The wordlist of a bip39 language.
+impl From<Language> for Wordlist<'static> {
+    fn from(language: Language) -> Self {
+        Wordlist {
+            words: language.word_list(),
+        }
+    }
+}
+
+impl Mnemonic {
+    /// Parse words of `list`, or their unique prefixes, into the English [Mnemonic] with the
+    /// same word indices, see the [module docs](crate::wordlist).
+    ///
+    /// Unknown words are [Error::UnknownWord], and the checksum is checked like
+    /// [Mnemonic::from_word_indices_in].
+    pub fn from_words_with_list(list: &Wordlist, s: &str) -> Result<Self, SeedXorError> {
+        Self::from_word_indices_in(Language::English, &list.indices(s)?)
+    }
+
+    /// The words of `list` at this mnemonic's word indices separated by spaces, the inverse of
+    /// [Mnemonic::from_words_with_list].
+    pub fn reveal_with_list(&self, list: &Wordlist) -> String {
+        let language = self.language();
+        let mut ret = self.word_iter().fold(String::new(), |mut s, word| {
+            let index = language
+                .find_word(word)
+                .expect("words are of their language");
+            s.push_str(list.words[index as usize]);
+            s.push(' ');
+            s
+        });
+        ret.pop();
+        ret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wordlist::*;
+    #[cfg(feature = "std")]
+    use crate::SeedXor;
+    #[cfg(feature = "std")]
+    use std::str::FromStr;
+
+    /// `w0000` to `w2047`, the only real word being `abandon` in place of `w0001`.
+    fn words() -> [&'static str; 2048] {
+        let words: Vec<&'static str> = (0..2048)
+            .map(|i| match i {
+                1 => "abandon",
+                _ => &*String::leak(format!("w{i:04}")),
+            })
+            .collect();
+        words.try_into().unwrap()
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn custom_list_roundtrip() {
+        let words = words();
+        let list = Wordlist::new(&words).unwrap();
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let seed = Mnemonic::from_str(seed).unwrap();
+        let custom = seed.reveal_with_list(&list);
+        assert_eq!(
+            "w1603 w1820 w1104 w1348 w0302 w0192 w1971 w1222 w1798 w2031 w1245 w0213",
+            custom
+        );
+        assert_eq!(
+            seed,
+            Mnemonic::from_words_with_list(&list, &custom).unwrap()
+        );
+
+        let shares: Vec<String> = seed
+            .clone()
+            .splitn(3)
+            .unwrap()
+            .iter()
+            .map(|share| share.reveal_with_list(&list))
+            .collect();
+        let shares: Vec<Mnemonic> = shares
+            .iter()
+            .map(|share| Mnemonic::from_words_with_list(&list, share).unwrap())
+            .collect();
+        assert_eq!(
+            custom,
+            Mnemonic::xor_all(&shares).unwrap().reveal_with_list(&list)
+        );
+
+        let english = Wordlist::from(Language::English);
+        assert_eq!(seed.reveal(), seed.reveal_with_list(&english));
+    }
+
+    #[test]
+    fn custom_list_expand_and_errors() {
+        let words = words();
+        let list = Wordlist::new(&words).unwrap();
+        assert_eq!(
+            "abandon w0002 w2047",
+            list.expand_words("ab w0002 w2047").unwrap()
+        );
+        // w000 starts w0000 and w0002 to w0009
+        assert_eq!(
+            Err(Error::UnknownWord(1).into()),
+            list.expand_words("ab w000")
+        );
+        assert_eq!(
+            Err(Error::UnknownWord(0).into()),
+            list.expand_words("W0002")
+        );
+        assert_eq!(
+            Err(Error::InvalidChecksum.into()),
+            Mnemonic::from_words_with_list(&list, &["w0000"; 12].join(" "))
+        );
+        assert_eq!(
+            Err(Error::BadWordCount(11).into()),
+            Mnemonic::from_words_with_list(&list, &["w0000"; 11].join(" "))
+        );
+
+        let mut bad = words;
+        bad[7] = "w0003";
+        assert_eq!(Err(Error::UnknownWord(7).into()), Wordlist::new(&bad));
+        bad[7] = "two words";
+        assert_eq!(Err(Error::UnknownWord(7).into()), Wordlist::new(&bad));
+        bad[7] = "";
+        assert_eq!(Err(Error::UnknownWord(7).into()), Wordlist::new(&bad));
+    }
+}
@@ -0,0 +1,205 @@
+//! Reproducible shares from a key using the SHAKE256 extendable-output function
+//! ([FIPS 202](https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.202.pdf)).
+
+use crate::{Mnemonic, SeedXor, SeedXorError, MAX_SPLIT};
+use alloc::{vec, vec::Vec};
+
+const RATE: usize = 136;
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+const ROTATIONS: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+const LANES: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+fn keccak_f(state: &mut [u64; 25]) {
+    for rc in ROUND_CONSTANTS {
+        // theta
+        let mut columns = [0u64; 5];
+        for (x, column) in columns.iter_mut().enumerate() {
+            *column = (0..25).step_by(5).fold(0, |c, y| c ^ state[y + x]);
+        }
+        for x in 0..5 {
+            let t = columns[(x + 4) % 5] ^ columns[(x + 1) % 5].rotate_left(1);
+            for y in (0..25).step_by(5) {
+                state[y + x] ^= t;
+            }
+        }
+        // rho and pi
+        let mut last = state[1];
+        for (lane, rotation) in LANES.iter().zip(ROTATIONS) {
+            let t = state[*lane];
+            state[*lane] = last.rotate_left(rotation);
+            last = t;
+        }
+        // chi
+        for y in (0..25).step_by(5) {
+            let row: [u64; 5] = state[y..y + 5].try_into().expect("5 lanes");
+            for x in 0..5 {
+                state[y + x] ^= !row[(x + 1) % 5] & row[(x + 2) % 5];
+            }
+        }
+        // iota
+        state[0] ^= rc;
+    }
+}
+
+fn xor_block(state: &mut [u64; 25], block: &[u8]) {
+    for (i, b) in block.iter().enumerate() {
+        state[i / 8] ^= (*b as u64) << (8 * (i % 8));
+    }
+}
+
+/// Fill `out` with the SHAKE256 output for `input`.
+pub(crate) fn shake256(input: &[u8], out: &mut [u8]) {
+    let mut state = [0u64; 25];
+    let mut blocks = input.chunks_exact(RATE);
+    for block in &mut blocks {
+        xor_block(&mut state, block);
+        keccak_f(&mut state);
+    }
+    let mut last = [0u8; RATE];
+    let rest = blocks.remainder();
+    last[..rest.len()].copy_from_slice(rest);
+    last[rest.len()] ^= 0x1f;
+    last[RATE - 1] ^= 0x80;
+    xor_block(&mut state, &last);
+
+    for chunk in out.chunks_mut(RATE) {
+        keccak_f(&mut state);
+        for (i, b) in chunk.iter_mut().enumerate() {
+            *b = (state[i / 8] >> (8 * (i % 8))) as u8;
+        }
+    }
+}
+
+impl Mnemonic {
+    /// Split into `n` shares where the first `n - 1` are pads derived from `key` with SHAKE256,
+    /// and the last is the residual XOR of this mnemonic and every pad.
+    ///
+    /// The same key always derives the same pads, so only `key` and the residual share need
+    /// to be backed up to regenerate every share. `key` must have at least as much entropy as
+    /// this mnemonic or the shares protect nothing.
+    ///
+    /// Like [Mnemonic::splitn], `n` over [MAX_SPLIT] is [SeedXorError::TooManyShares] and
+    /// no shares at all is [SeedXorError::EmptyInput].
+    pub fn splitn_xof(&self, n: usize, key: &[u8]) -> Result<Vec<Self>, SeedXorError> {
+        if n == 0 {
+            return Err(SeedXorError::EmptyInput);
+        }
+        if n > MAX_SPLIT {
+            return Err(SeedXorError::TooManyShares {
+                max: MAX_SPLIT,
+                got: n,
+            });
+        }
+        let (mut entropy, entropy_len) = self.to_entropy_array();
+        crate::wipe(&mut entropy);
+        let mut ret = Vec::with_capacity(n);
+        let mut residual = self.clone();
+        for counter in 0..(n - 1) as u32 {
+            let mut input = key.to_vec();
+            input.extend_from_slice(&counter.to_be_bytes());
+            let mut pad = vec![0u8; entropy_len];
+            shake256(&input, &mut pad);
+            let mnemonic = bip39::Mnemonic::from_entropy_in(self.language(), &pad);
+            crate::wipe(&mut input);
+            crate::wipe(&mut pad);
+            let pad: Mnemonic = mnemonic?.into();
+            residual = residual.xor(&pad);
+            ret.push(pad);
+        }
+        ret.push(residual);
+        Ok(ret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::xof::*;
+    use std::str::FromStr;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn shake256_vectors() {
+        let mut out = [0u8; 32];
+        shake256(b"", &mut out);
+        assert_eq!(
+            "46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762f",
+            hex(&out)
+        );
+        shake256(b"abc", &mut out);
+        assert_eq!(
+            "483366601360a8771c6863080cc4114d8db44530f8f1e1ee4f94ea37e78b5739",
+            hex(&out)
+        );
+
+        // output longer than one block continues the same stream
+        let mut long = [0u8; 300];
+        shake256(b"abc", &mut long);
+        assert_eq!(out, long[0..32]);
+        // as does input longer than one block
+        shake256(&[0xa3; 200], &mut out);
+        assert_eq!(
+            "cd8a920ed141aa0407a22d59288652e9d9f1a7ee0c1e7c1ca699424da84a904d",
+            hex(&out)
+        );
+    }
+
+    #[test]
+    fn splitn_xof_works() {
+        let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+        let seed = Mnemonic::from_str(seed).unwrap();
+        let key = b"correct horse battery staple, but much longer and more random";
+
+        for x in 1..=5 {
+            let split = seed.splitn_xof(x, key).unwrap();
+            assert_eq!(x, split.len());
+            assert_eq!(split, seed.splitn_xof(x, key).unwrap());
+            assert_eq!(seed, Mnemonic::xor_all(&split).unwrap());
+        }
+
+        // pads only depend on the key and their position
+        let three = seed.splitn_xof(3, key).unwrap();
+        let four = seed.splitn_xof(4, key).unwrap();
+        assert_eq!(three[0..2], four[0..2]);
+        assert_ne!(three, seed.splitn_xof(3, b"another key").unwrap());
+        assert_eq!(Err(SeedXorError::EmptyInput), seed.splitn_xof(0, key));
+        assert_eq!(
+            Err(SeedXorError::TooManyShares {
+                max: MAX_SPLIT,
+                got: MAX_SPLIT + 1
+            }),
+            seed.splitn_xof(MAX_SPLIT + 1, key)
+        );
+    }
+}
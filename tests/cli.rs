@@ -0,0 +1,853 @@
+use std::{
+    io::Write,
+    process::{Command, Output, Stdio},
+};
+
+fn seedxor(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_seedxor"))
+        .args(args)
+        .output()
+        .expect("could not run seedxor")
+}
+
+fn seedxor_stdin(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_seedxor"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("could not run seedxor");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().expect("could not run seedxor")
+}
+
+#[test]
+fn benchmark() {
+    let output = seedxor(&["--benchmark"]);
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("splits/combines: "), "{stderr}");
+    assert!(
+        stderr.contains("unscramble of 362880 permutations: "),
+        "{stderr}"
+    );
+    assert!(
+        stderr.contains("unscramble of 5040 permutations: parsed "),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn unscramble_fix_checksum() {
+    let parts = [
+        "worth option",
+        "silent toe meat",
+        "wait occur this",
+        "possible chair blossom",
+    ];
+    let mut args = vec!["-u"];
+    args.extend_from_slice(&parts);
+    let output = seedxor(&args);
+    assert!(output.status.success());
+    // the checksum word is missing so nothing can be valid
+    assert!(output.stdout.is_empty());
+
+    args.push("--fix-checksum");
+    let output = seedxor(&args);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout
+        .lines()
+        .any(|l| l == "silent toe meat possible chair blossom wait occur this worth option boy"));
+    assert_eq!(24 * 128, stdout.lines().count());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("# good: 24 bad: 0 total: 24"), "{stderr}");
+}
+
+#[test]
+fn split_shuffle_order() {
+    let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+    let output = seedxor(&["-s", seed, "-n", "4", "-z"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let mut args = vec!["-c"];
+    args.extend(stdout.lines());
+    assert_eq!(4, args.len() - 1);
+    let output = seedxor(&args);
+    assert!(output.status.success());
+    assert_eq!(
+        format!("{seed}\n"),
+        String::from_utf8(output.stdout).unwrap()
+    );
+}
+
+#[test]
+fn split_output_dir() {
+    let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+    let dir = std::env::temp_dir().join(format!("seedxor-output-dir-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let output = seedxor(&["-s", seed, "-n", "3", "--output-dir", dir.to_str().unwrap()]);
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    let shares: Vec<String> = (1..=3)
+        .map(|i| {
+            let path = dir.join(format!("share-{i}.txt"));
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+                assert_eq!(0o600, mode & 0o777);
+            }
+            std::fs::read_to_string(path).unwrap().trim().to_owned()
+        })
+        .collect();
+    let mut args = vec!["-c"];
+    args.extend(shares.iter().map(String::as_str));
+    let output = seedxor(&args);
+    assert_eq!(
+        format!("{seed}\n"),
+        String::from_utf8(output.stdout).unwrap()
+    );
+
+    // existing shares are never overwritten
+    let output = seedxor(&["-s", seed, "-n", "3", "-o", dir.to_str().unwrap()]);
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("error: cannot write "), "{stdout}");
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn split_output_dir_short_and_mask() {
+    let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+    let dir = std::env::temp_dir().join(format!("seedxor-output-short-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let output = seedxor(&[
+        "-s",
+        seed,
+        "-n",
+        "2",
+        "-o",
+        dir.to_str().unwrap(),
+        "--short",
+    ]);
+    assert!(output.status.success());
+    let mut shares = Vec::new();
+    for i in 1..=2 {
+        let words = std::fs::read_to_string(dir.join(format!("share-{i}.txt"))).unwrap();
+        let short = std::fs::read_to_string(dir.join(format!("share-{i}.short.txt"))).unwrap();
+        assert_eq!(12, words.split_whitespace().count());
+        assert!(words.split_whitespace().any(|w| w.len() > 4), "{words}");
+        assert!(short.split_whitespace().all(|w| w.len() <= 4), "{short}");
+        shares.push(words.trim().to_owned());
+    }
+    let output = seedxor(&["-c", &shares[0], &shares[1]]);
+    assert_eq!(
+        format!("{seed}\n"),
+        String::from_utf8(output.stdout).unwrap()
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let output = seedxor(&["-s", seed, "-o", dir.to_str().unwrap(), "--mask", "2"]);
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains("without --qr, --json or --mask"),
+        "{stdout}"
+    );
+    assert!(!dir.exists());
+}
+
+#[test]
+fn split_max_depth() {
+    let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+    let output = seedxor(&["-s", seed, "-n", "18446744073709551615"]);
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("more than max-depth 10000"), "{stdout}");
+
+    let output = seedxor(&["-s", seed, "-n", "4", "--max-depth", "3"]);
+    assert!(!output.status.success());
+    let output = seedxor(&["-s", seed, "-n", "4", "--max-depth", "4"]);
+    assert!(output.status.success());
+    assert_eq!(4, String::from_utf8(output.stdout).unwrap().lines().count());
+}
+
+#[cfg(feature = "bip32")]
+#[test]
+fn combine_verify_xpub() {
+    // identical shares xor to all zero entropy, the "abandon ... about" BIP84 test vector seed
+    let shares = [
+        "legal winner thank year wave sausage worth useful legal winner thank yellow",
+        "legal winner thank year wave sausage worth useful legal winner thank yellow",
+    ];
+    let zpub = "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs";
+    let output = seedxor(&["-c", shares[0], shares[1], "--verify-xpub", zpub]);
+    assert!(output.status.success());
+    assert_eq!("MATCH\n", String::from_utf8(output.stdout).unwrap());
+
+    let output = seedxor(&["-c", shares[0], "--verify-xpub", zpub]);
+    assert!(!output.status.success());
+    assert_eq!("NO MATCH\n", String::from_utf8(output.stdout).unwrap());
+
+    let output = seedxor(&["-c", shares[0], "--verify-xpub", "zpub6rFR7y4Q2Aij"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stdout)
+        .unwrap()
+        .starts_with("error: invalid xpub"));
+}
+
+#[test]
+fn combine_args_and_stdin() {
+    let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+    let output = seedxor(&["-s", seed, "-n", "3"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let shares: Vec<&str> = stdout.lines().collect();
+    assert_eq!(3, shares.len());
+
+    let output = seedxor_stdin(
+        &["-c", shares[0], "-", shares[2]],
+        &format!("{}\n\n", shares[1]),
+    );
+    assert!(output.status.success());
+    assert_eq!(
+        format!("{seed}\n"),
+        String::from_utf8(output.stdout).unwrap()
+    );
+}
+
+#[test]
+fn split_stdin() {
+    let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+    let output = seedxor_stdin(&["-s", "-", "-n", "3"], &format!("  {seed}\n"));
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let shares: Vec<&str> = stdout.lines().collect();
+    assert_eq!(3, shares.len());
+    assert!(!shares.contains(&seed));
+
+    let output = seedxor_stdin(&["-c", "-"], &stdout);
+    assert!(output.status.success());
+    assert_eq!(
+        format!("{seed}\n"),
+        String::from_utf8(output.stdout).unwrap()
+    );
+
+    // short words expand like on the command line
+    let output = seedxor_stdin(
+        &["-s", "-"],
+        "sile toe meat poss chai blos wait occu this wort opti boy",
+    );
+    assert!(output.status.success());
+    let output = seedxor_stdin(&["-c", "-"], &String::from_utf8(output.stdout).unwrap());
+    assert_eq!(
+        format!("{seed}\n"),
+        String::from_utf8(output.stdout).unwrap()
+    );
+
+    let output = seedxor_stdin(&["-s", "-"], "");
+    assert!(!output.status.success());
+}
+
+#[test]
+fn qr_output() {
+    let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+    let output = seedxor(&["-q", "-c", seed]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    // version 5, 37 modules and the quiet zone, two rows per line
+    assert_eq!(23, stdout.lines().filter(|l| !l.is_empty()).count());
+    assert!(!stdout.contains("silent"));
+
+    let output = seedxor(&["--qr", "-n", "3", "-s", seed]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(3, stdout.split("\n\n").filter(|qr| !qr.is_empty()).count());
+
+    let output = seedxor(&["-q", "-r", "-g", "-n", "1"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    // 24 short words fit in version 7, 45 modules
+    assert_eq!(27, stdout.lines().filter(|l| !l.is_empty()).count());
+}
+
+#[test]
+fn verify() {
+    let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+    let output = seedxor(&["-s", seed, "-n", "3"]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let shares: Vec<&str> = stdout.lines().collect();
+
+    let mut args = vec!["--verify", seed];
+    args.extend_from_slice(&shares);
+    let output = seedxor(&args);
+    assert!(output.status.success());
+    assert_eq!("OK\n", String::from_utf8(output.stdout).unwrap());
+
+    let output = seedxor_stdin(&["-v", seed, shares[0], "-"], &shares[1..].join("\n"));
+    assert!(output.status.success());
+    assert_eq!("OK\n", String::from_utf8(output.stdout).unwrap());
+
+    let output = seedxor(&["-v", seed, shares[0], shares[1]]);
+    assert!(!output.status.success());
+    assert_eq!("FAIL\n", String::from_utf8(output.stdout).unwrap());
+
+    // a 24 word share pads the 12 word seed with zeros
+    let zeros = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art";
+    let output = seedxor(&["-v", seed, seed, zeros, seed, zeros, seed]);
+    assert!(output.status.success());
+
+    // electrum seeds have no valid bip39 checksum
+    let electrum = "ramp exotic resource icon sun addict equip sand leisure spare swing tobacco";
+    let output = seedxor(&["-y", "-s", electrum]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let mut args = vec!["-y", "-v", electrum];
+    args.extend(stdout.lines());
+    let output = seedxor(&args);
+    assert!(output.status.success());
+    assert_eq!("OK\n", String::from_utf8(output.stdout).unwrap());
+    let output = seedxor(&["-y", "-s", electrum, "-n", "3", "--max-retries", "0"]);
+    assert!(output.status.success());
+    assert_eq!(3, String::from_utf8(output.stdout).unwrap().lines().count());
+}
+
+#[test]
+fn generate_dice() {
+    let rolls: String = (0..100).map(|i| char::from(b'1' + i % 6)).collect();
+    let output = seedxor(&["-g", "--dice", &rolls]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(1, stdout.lines().count());
+    assert_eq!(24, stdout.split_whitespace().count());
+
+    let output = seedxor_stdin(&["-g", "-d", "-"], &format!("{rolls}\n"));
+    assert_eq!(stdout, String::from_utf8(output.stdout).unwrap());
+
+    let output = seedxor(&["-g", "-w", "12", "-d", &rolls[..50]]);
+    assert!(output.status.success());
+    let output = seedxor(&["-g", "-d", &rolls[..99]]);
+    assert!(!output.status.success());
+    let output = seedxor(&["-g", "-w", "12", "-d", &rolls.replace('3', "7")]);
+    assert!(!output.status.success());
+}
+
+#[test]
+fn generate_entropy() {
+    // bip39 test vectors
+    let output = seedxor(&["-g", "-e", &"7f".repeat(16)]);
+    assert!(output.status.success());
+    assert_eq!(
+        "legal winner thank year wave sausage worth useful legal winner thank yellow\n",
+        String::from_utf8(output.stdout).unwrap()
+    );
+    let output = seedxor_stdin(&["-g", "-w", "24", "--entropy", "-"], &"00".repeat(32));
+    assert!(output.status.success());
+    assert_eq!(
+        format!("{}art\n", "abandon ".repeat(23)),
+        String::from_utf8(output.stdout).unwrap()
+    );
+
+    for args in [
+        &["-g", "-w", "24", "-e", &"7f".repeat(16)][..],
+        &["-g", "-n", "3", "-e", &"7f".repeat(16)],
+        &["-g", "-e", &"7f".repeat(15)],
+        &["-g", "-e", "7g"],
+    ] {
+        let output = seedxor(args);
+        assert!(!output.status.success(), "{args:?}");
+    }
+}
+
+#[test]
+fn unscramble_threads() {
+    let parts = [
+        "-u",
+        "worth option",
+        "silent toe meat",
+        "wait occur this",
+        "possible chair blossom",
+        "-f",
+    ];
+    let lines = |output: std::process::Output| {
+        assert!(output.status.success());
+        let mut lines: Vec<String> = String::from_utf8(output.stdout)
+            .unwrap()
+            .lines()
+            .map(str::to_owned)
+            .collect();
+        lines.sort();
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(stderr.contains("# good: 24 bad: 0 total: 24"), "{stderr}");
+        lines
+    };
+    let single = lines(seedxor(&parts));
+    let mut args = parts.to_vec();
+    args.extend_from_slice(&["-t", "3"]);
+    assert_eq!(single, lines(seedxor(&args)));
+}
+
+#[test]
+fn unscramble_finds_seed() {
+    let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+    for threads in ["1", "4"] {
+        let output = seedxor(&[
+            "-u",
+            "worth option boy",
+            "silent toe meat",
+            "wait occur this",
+            "possible chair blossom",
+            "-t",
+            threads,
+        ]);
+        assert!(output.status.success());
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(stdout.lines().any(|l| l == seed), "{stdout}");
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        let good = stdout.lines().count();
+        assert!(
+            stderr.contains(&format!("# good: {good} bad: {} total: 24", 24 - good)),
+            "{stderr}"
+        );
+    }
+}
+
+#[test]
+fn unscramble_anchors() {
+    let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+    let output = seedxor(&[
+        "-u",
+        "3=meat",
+        "silent toe",
+        "possible chair",
+        "blossom wait",
+        "occur this",
+        "12=boy",
+        "worth option",
+    ]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.lines().any(|l| l == seed), "{stdout}");
+    assert!(stdout
+        .lines()
+        .all(|l| l.split(' ').nth(2) == Some("meat") && l.ends_with(" boy")));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("# total permutations: 120"), "{stderr}");
+
+    for anchor in ["0=meat", "13=meat"] {
+        let output = seedxor(&["-u", anchor, "silent toe", "possible chair"]);
+        assert!(!output.status.success());
+    }
+    let output = seedxor(&["-u", "0=meat", "silent toe", "possible chair"]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.starts_with("error: anchor 0=meat has no position 0, positions count from 1"),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn unscramble_count_and_first() {
+    let parts = [
+        "-u",
+        "worth option boy",
+        "silent toe meat",
+        "wait occur this",
+        "possible chair blossom",
+    ];
+    let all = String::from_utf8(seedxor(&parts).stdout).unwrap();
+    let good = all.lines().count();
+
+    let mut args = parts.to_vec();
+    args.push("--count");
+    let output = seedxor(&args);
+    assert!(output.status.success());
+    assert_eq!(
+        format!("good: {good} bad: {} total: 24\n", 24 - good),
+        String::from_utf8(output.stdout).unwrap()
+    );
+
+    for threads in ["1", "3"] {
+        let mut args = parts.to_vec();
+        args.extend_from_slice(&["--first", "-t", threads]);
+        let output = seedxor(&args);
+        assert!(output.status.success());
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert_eq!(1, stdout.lines().count(), "{stdout}");
+        assert!(all.lines().any(|l| l == stdout.trim()), "{stdout}");
+    }
+}
+
+#[test]
+fn combine_masked() {
+    let output = seedxor(&[
+        "-c",
+        "silent toe meat possible chair blossom wait occur this worth option boy",
+        "-k",
+        "2",
+    ]);
+    assert!(output.status.success());
+    assert_eq!(
+        "silent toe •••• •••• •••• •••• •••• •••• •••• •••• option boy\n",
+        String::from_utf8(output.stdout).unwrap()
+    );
+
+    let output = seedxor(&[
+        "-c",
+        "silent toe meat possible chair blossom wait occur this worth option boy",
+        "--mask",
+        "x",
+    ]);
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("error: invalid --mask\n"), "{stdout}");
+}
+
+#[cfg(feature = "bip32")]
+#[test]
+fn combine_fingerprint() {
+    // identical shares xor to all zero entropy, "abandon ... about"
+    let share = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+    let output = seedxor(&["-c", share, share, "-p"]);
+    assert!(output.status.success());
+    assert_eq!(
+        format!("73c5da0a  {}about\n", "abandon ".repeat(11)),
+        String::from_utf8(output.stdout).unwrap()
+    );
+
+    let output = seedxor(&["-c", share, share, "--fingerprint", "-k", "1"]);
+    assert!(String::from_utf8(output.stdout)
+        .unwrap()
+        .starts_with("73c5da0a  abandon ••••"));
+}
+
+#[test]
+fn bad_word_named() {
+    let output = seedxor(&[
+        "-c",
+        "silent toe meat possible chair blossom wait occur thiz worth option boy",
+    ]);
+    assert!(!output.status.success());
+    assert_eq!(
+        "error: bad word 'thiz' at position 9\n",
+        String::from_utf8(output.stdout).unwrap()
+    );
+
+    let output = seedxor(&["-u", "silent toe", "meat tow"]);
+    assert!(!output.status.success());
+    assert_eq!(
+        "error: bad word 'tow' at position 2\n",
+        String::from_utf8(output.stdout).unwrap()
+    );
+}
+
+#[test]
+fn combine_strict() {
+    let short = "silent toe meat possible chair blossom wait occur this worth option boy";
+    let long = "romance wink lottery autumn shop bring dawn tongue range crater truth ability miss spice fitness easy legal release recall obey exchange recycle dragon room";
+    let output = seedxor(&["-c", short, long]);
+    assert!(output.status.success());
+
+    let output = seedxor(&["-c", short, long, "--strict"]);
+    assert!(!output.status.success());
+    assert_eq!(
+        "error: cannot combine seeds: expected 12 items but got 24\n",
+        String::from_utf8(output.stdout).unwrap()
+    );
+    let output = seedxor(&["-c", short, short, "--strict"]);
+    assert!(output.status.success());
+}
+
+#[test]
+fn generate_bad_word_count() {
+    for words in ["12", "15", "18", "21", "24"] {
+        let output = seedxor(&["-g", "-n", "1", "-w", words]);
+        assert!(output.status.success());
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert_eq!(words, stdout.split_whitespace().count().to_string());
+    }
+    for words in ["0", "13", "25"] {
+        let output = seedxor(&["-g", "-w", words]);
+        assert!(!output.status.success());
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(
+            stdout.starts_with("error: cannot generate seed"),
+            "{stdout}"
+        );
+    }
+}
+
+#[test]
+fn last_word() {
+    let words = "romance wink lottery autumn shop bring dawn tongue range crater truth ability miss spice fitness easy legal release recall obey exchange recycle dragon";
+    let output = seedxor(&["-l", words]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(8, stdout.lines().count(), "{stdout}");
+    assert!(stdout.lines().any(|l| l == "room"), "{stdout}");
+
+    // separate and shortened words work too
+    let output = seedxor(&[
+        "--last-word",
+        "sile",
+        "toe",
+        "meat",
+        "poss",
+        "chai",
+        "blos",
+        "wait",
+        "occu",
+        "this",
+        "wort",
+        "opti",
+    ]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(128, stdout.lines().count(), "{stdout}");
+    assert!(stdout.lines().any(|l| l == "boy"), "{stdout}");
+
+    let output = seedxor(&["-l", "silent toe meat"]);
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains("needs 11, 14, 17, 20 or 23 words, got 3"),
+        "{stdout}"
+    );
+
+    let output = seedxor(&[
+        "-l",
+        "silent toe meat possible chair blossom wait occur this worth optionn",
+    ]);
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains("bad word 'optionn' at position 11"),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn generate_coins() {
+    let flips = "HT".repeat(128);
+    let output = seedxor(&["-g", "--coins", &flips]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    // 0xaa repeated
+    let output = seedxor(&["-g", "-e", &"aa".repeat(32)]);
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), stdout);
+
+    let output = seedxor_stdin(
+        &["-g", "--coins", "-"],
+        &format!("{}\n", flips.to_lowercase()),
+    );
+    assert_eq!(stdout, String::from_utf8(output.stdout).unwrap());
+
+    let output = seedxor(&["-g", "-w", "12", "--coins", &flips[..128]]);
+    assert!(output.status.success());
+    assert_eq!(
+        12,
+        String::from_utf8(output.stdout)
+            .unwrap()
+            .split_whitespace()
+            .count()
+    );
+
+    let output = seedxor(&["-g", "--coins", &flips[..255]]);
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains("24 words need exactly 256 coin flips, got 255"),
+        "{stdout}"
+    );
+
+    let output = seedxor(&["-g", "--coins", &flips.replacen('T', "x", 1)]);
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains("coin flip 'x' at position 2 is not H or T"),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn language() {
+    let output = seedxor(&[
+        "--language",
+        "English",
+        "-l",
+        "sile toe meat poss chai blos wait occu this wort opti",
+    ]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.lines().any(|l| l == "boy"), "{stdout}");
+
+    let output = seedxor(&["--language", "english", "-g", "-n", "1", "-w", "12"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(12, stdout.split_whitespace().count(), "{stdout}");
+
+    let output = seedxor(&["--language", "klingon", "-g"]);
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.starts_with("error: unknown language 'klingon', supported: english"),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn json() {
+    let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+    let short = "sile toe  meat poss chai blos wait occu this wort opti boy";
+    let output = seedxor(&["--json", "-c", seed]);
+    assert!(output.status.success());
+    assert_eq!(
+        format!("{{\"words\":\"{seed}\",\"short\":\"{short}\"}}\n"),
+        String::from_utf8(output.stdout).unwrap()
+    );
+
+    let output = seedxor(&["--json", "-s", seed, "-n", "3"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("[{\"index\":1,\"words\":\""), "{stdout}");
+    let shares: Vec<&str> = stdout
+        .split("\"words\":\"")
+        .skip(1)
+        .map(|s| s.split('"').next().unwrap())
+        .collect();
+    assert_eq!(3, shares.len(), "{stdout}");
+    let mut args = vec!["-c"];
+    args.extend(shares);
+    let output = seedxor(&args);
+    assert_eq!(
+        format!("{seed}\n"),
+        String::from_utf8(output.stdout).unwrap()
+    );
+
+    let output = seedxor(&[
+        "--json",
+        "-u",
+        "silent toe",
+        "meat possible chair blossom wait occur this worth option",
+        "boy",
+    ]);
+    assert!(output.status.success());
+    assert_eq!(
+        format!("{{\"valid\":[{{\"words\":\"{seed}\",\"short\":\"{short}\"}}],\"good\":1,\"bad\":5,\"total\":6,\"tried\":6}}\n"),
+        String::from_utf8(output.stdout).unwrap()
+    );
+
+    let output = seedxor(&["--json", "-g"]);
+    assert!(!output.status.success());
+}
+
+#[test]
+fn grid() {
+    let seed = "silent toe meat possible chair blossom wait occur this worth option boy";
+    let output = seedxor(&["--grid", "-c", seed]);
+    assert!(output.status.success());
+    assert_eq!(
+        " 1. silent     2. toe        3. meat       4. possible
+ 5. chair      6. blossom    7. wait       8. occur
+ 9. this      10. worth     11. option    12. boy
+
+",
+        String::from_utf8(output.stdout).unwrap()
+    );
+
+    let output = seedxor(&["--grid", "--short", "-c", seed]);
+    assert_eq!(
+        " 1. sile   2. toe    3. meat   4. poss
+ 5. chai   6. blos   7. wait   8. occu
+ 9. this  10. wort  11. opti  12. boy
+
+",
+        String::from_utf8(output.stdout).unwrap()
+    );
+}
+
+#[cfg(feature = "all-languages")]
+#[test]
+fn combine_languages() {
+    let output = seedxor(&[
+        "--language",
+        "japanese",
+        "-g",
+        "-e",
+        "ff".repeat(16).as_str(),
+    ]);
+    let japanese = String::from_utf8(output.stdout).unwrap();
+    let japanese = japanese.trim();
+    let english = "silent toe meat possible chair blossom wait occur this worth option boy";
+    let output = seedxor(&["-c", japanese, english]);
+    assert!(output.status.success());
+    assert_eq!(
+        "daring broccoli invest first surface tree another grunt busy acoustic gold tone\n",
+        String::from_utf8(output.stdout).unwrap()
+    );
+    assert_eq!(
+        "# warning: combining seeds of different languages (Japanese, English), the result is English\n",
+        String::from_utf8(output.stderr).unwrap()
+    );
+
+    let output = seedxor(&["--strict", "-c", japanese, english]);
+    assert!(!output.status.success());
+    assert_eq!(
+        "error: cannot combine seeds of different languages: Japanese, English\n",
+        String::from_utf8(output.stdout).unwrap()
+    );
+}
+
+#[cfg(feature = "bitcoin")]
+#[test]
+fn address() {
+    let seed = format!("{}about", "abandon ".repeat(11));
+    let output = seedxor(&["-a", &seed]);
+    assert!(output.status.success());
+    // from the BIP44, BIP49 and BIP84 test vectors
+    assert_eq!(
+        "m/44'/0'/0'/0/0  1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA
+m/49'/0'/0'/0/0  37VucYSaXLCAsxYyAPfbSi9eh4iEcbShgf
+m/84'/0'/0'/0/0  bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu
+",
+        String::from_utf8(output.stdout).unwrap()
+    );
+
+    let output = seedxor_stdin(
+        &[
+            "--address",
+            "-",
+            "--path",
+            "m/49'/1'/0'/0/0",
+            "--network",
+            "testnet",
+        ],
+        &seed,
+    );
+    assert!(output.status.success());
+    assert_eq!(
+        "m/49'/1'/0'/0/0  2Mww8dCYPUpKHofjgcXcBCEGmniw9CoaiD2\n",
+        String::from_utf8(output.stdout).unwrap()
+    );
+
+    let output = seedxor(&["-a", &seed, "--path", "m/84'/0'/x"]);
+    assert!(!output.status.success());
+    assert_eq!(
+        "error: bad part 'x' at position 4 of path 'm/84'/0'/x'\n",
+        String::from_utf8(output.stdout).unwrap()
+    );
+
+    let output = seedxor(&["-a", &seed, "--path", "m/86'/0'/0'/0/0"]);
+    assert!(!output.status.success());
+    assert_eq!(
+        "error: invalid path 'm/86'/0'/0'/0/0': unsupported purpose 86', supported: 44', 49', 84'\n",
+        String::from_utf8(output.stdout).unwrap()
+    );
+}